@@ -1,6 +1,13 @@
+mod app_entry_config;
+mod binding_config;
+mod focus_config;
 mod group_config;
+mod launch_config;
 mod lists;
+mod outcome_log;
 mod root;
+mod rule_config;
+mod settings_config;
 mod util;
 
 pub use root::Root;
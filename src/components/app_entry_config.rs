@@ -0,0 +1,81 @@
+use std::path::PathBuf;
+
+use dioxus::prelude::*;
+use uuid::Uuid;
+
+use crate::models::{LaunchOptions, Member};
+use crate::services::ConfigService;
+
+#[component]
+pub fn AppEntryConfig(
+    config_service: Signal<ConfigService>,
+    group_id: Uuid,
+    app_id: String,
+) -> Element {
+    let my_app_id = app_id.clone();
+    let launch_options = use_memo(move || {
+        let config = config_service.read();
+        let group = config.group(group_id).unwrap();
+        group
+            .member(&my_app_id)
+            .and_then(Member::as_app)
+            .map(|a| a.launch_options.clone())
+            .unwrap_or_default()
+    });
+    let mut args = use_signal(|| launch_options().args.join("\n"));
+    let working_dir = use_signal(|| {
+        launch_options()
+            .working_dir
+            .map(|d| d.to_string_lossy().into_owned())
+            .unwrap_or_default()
+    });
+    let mut env = use_signal(|| launch_options().format_env());
+    use_effect(move || {
+        let launch_options = LaunchOptions {
+            args: LaunchOptions::parse_args(&args()),
+            working_dir: (!working_dir().is_empty()).then(|| PathBuf::from(working_dir())),
+            env: LaunchOptions::parse_env(&env()),
+        };
+        config_service
+            .write()
+            .set_launch_options(group_id, &app_id, launch_options);
+    });
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-2 text-sm",
+            span { class: "font-bold", "Launch options" }
+            textarea {
+                class: "textarea textarea-sm",
+                placeholder: "Arguments, one per line",
+                value: "{args}",
+                oninput: move |evt| args.set(evt.value()),
+            }
+            {render_working_dir(working_dir)}
+            textarea {
+                class: "textarea textarea-sm",
+                placeholder: "Environment, KEY=VALUE per line",
+                value: "{env}",
+                oninput: move |evt| env.set(evt.value()),
+            }
+        }
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn render_working_dir(mut working_dir: Signal<String>) -> Element {
+    rsx! {
+        input {
+            class: "input input-sm",
+            placeholder: "Working directory",
+            value: "{working_dir}",
+            oninput: move |evt| working_dir.set(evt.value()),
+        }
+    }
+}
+
+// LaunchServices starts apps without a working directory
+#[cfg(target_os = "macos")]
+fn render_working_dir(_working_dir: Signal<String>) -> Element {
+    rsx! {}
+}
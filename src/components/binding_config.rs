@@ -0,0 +1,88 @@
+use dioxus::prelude::*;
+use uuid::Uuid;
+
+use crate::components::util::{
+    ActionPicker, ChordPicker, ContextPicker, EditableText, HotkeyPicker, HotkeyStatus,
+    TriggerPicker,
+};
+use crate::models::Action;
+use crate::services::ConfigService;
+
+#[component]
+pub fn BindingConfig(config_service: Signal<ConfigService>, binding_id: Uuid) -> Element {
+    let binding = use_memo(move || config_service.read().binding(binding_id).unwrap().clone());
+    let name = use_signal(|| binding().name.clone());
+    use_effect(move || config_service.write().set_binding_name(binding_id, name()));
+    let mut enabled = use_signal(|| binding().enabled);
+    use_effect(move || {
+        config_service
+            .write()
+            .set_binding_enabled(binding_id, enabled())
+    });
+    let picked_hotkey = use_signal(|| binding().shortcut.hotkey);
+    use_effect(move || {
+        config_service
+            .write()
+            .set_hotkey(Action::Binding { binding_id }, picked_hotkey());
+    });
+    let trigger = use_signal(|| binding().shortcut.trigger);
+    let mut trigger_conflict = use_signal(|| None::<String>);
+    use_effect(move || {
+        let conflict = config_service
+            .write()
+            .set_trigger(Action::Binding { binding_id }, trigger());
+        trigger_conflict.set(conflict.map(|a| config_service.peek().config().describe(&a)));
+    });
+    let context = use_signal(|| binding().shortcut.context.clone());
+    use_effect(move || {
+        config_service
+            .write()
+            .set_context(Action::Binding { binding_id }, context());
+    });
+    let picked_chord = use_signal(|| binding().shortcut.chord.clone());
+    let mut chord_conflict = use_signal(|| None::<String>);
+    use_effect(move || {
+        let conflict = config_service
+            .write()
+            .set_chord(Action::Binding { binding_id }, picked_chord());
+        chord_conflict.set(conflict.map(|a| config_service.peek().config().describe(&a)));
+    });
+    let action = use_signal(|| binding().action.clone());
+    use_effect(move || {
+        config_service
+            .write()
+            .set_binding_action(binding_id, action())
+    });
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-2",
+            EditableText { text: name }
+            label {
+                class: "flex items-center gap-2 text-sm",
+                input {
+                    class: "toggle toggle-sm",
+                    r#type: "checkbox",
+                    checked: enabled(),
+                    onchange: move |evt| enabled.set(evt.checked()),
+                }
+                "Enabled"
+            }
+            div {
+                class: "flex gap-2",
+                HotkeyPicker { picked_hotkey }
+                TriggerPicker { trigger }
+            }
+            HotkeyStatus { config_service, action: Action::Binding { binding_id } }
+            if let Some(conflict) = trigger_conflict() {
+                span { class: "text-sm text-error", "Used by '{conflict}' with this trigger" }
+            }
+            ChordPicker { picked_chord }
+            ContextPicker { context }
+            if let Some(conflict) = chord_conflict() {
+                span { class: "text-sm text-error", "Can't be told apart from '{conflict}'" }
+            }
+            ActionPicker { action }
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use dioxus::prelude::*;
+use uuid::Uuid;
+
+use crate::components::util::{ContextPicker, HotkeyPicker, HotkeyStatus};
+use crate::models::Action;
+use crate::services::ConfigService;
+
+#[component]
+pub fn FocusConfig(config_service: Signal<ConfigService>, group_id: Uuid) -> Element {
+    let group = use_memo(move || config_service.read().group(group_id).unwrap().clone());
+    let mut exclusive = use_signal(|| group().exclusive);
+    use_effect(move || config_service.write().set_exclusive(group_id, exclusive()));
+    let picked_hotkey = use_signal(|| group().restore_shortcut.hotkey);
+    use_effect(move || {
+        config_service
+            .write()
+            .set_hotkey(Action::RestoreHidden { group_id }, picked_hotkey());
+    });
+    let context = use_signal(|| group().restore_shortcut.context.clone());
+    use_effect(move || {
+        config_service
+            .write()
+            .set_context(Action::RestoreHidden { group_id }, context());
+    });
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-2",
+            label {
+                class: "flex items-center gap-2 text-sm",
+                input {
+                    class: "toggle toggle-sm",
+                    r#type: "checkbox",
+                    checked: exclusive(),
+                    onchange: move |evt| exclusive.set(evt.checked()),
+                }
+                "Hide other apps"
+            }
+            if exclusive() {
+                label {
+                    class: "flex items-center gap-2 text-sm",
+                    "Restore"
+                    HotkeyPicker { picked_hotkey }
+                }
+                HotkeyStatus { config_service, action: Action::RestoreHidden { group_id } }
+                ContextPicker { context }
+            }
+        }
+    }
+}
@@ -1,29 +1,356 @@
+use std::collections::HashSet;
+
 use dioxus::prelude::*;
 use futures_util::StreamExt;
 use uuid::Uuid;
 
+use crate::components::app_entry_config::AppEntryConfig;
+use crate::components::focus_config::FocusConfig;
+use crate::components::launch_config::LaunchConfig;
 use crate::components::lists::{AppList, ListOperation};
-use crate::components::util::{EditableText, HotkeyPicker};
-use crate::os::{AppDialog, AppSelection};
+use crate::components::rule_config::RuleConfig;
+use crate::components::util::{
+    ChordPicker, ContextPicker, EditableText, HotkeyPicker, HotkeyStatus, TriggerPicker,
+    WindowPolicyPicker, pick_app,
+};
+use crate::models::{
+    Action, AppEntry, AppMatcher, Group, GroupMode, Identifiable, Member, MemberFlags, MemberKind,
+    TitlePattern,
+};
+use crate::os::App;
 use crate::services::ConfigService;
 
+const PREVIEW_LIMIT: usize = 8;
+
 #[component]
 pub fn GroupConfig(config_service: Signal<ConfigService>, group_id: Uuid) -> Element {
-    let group = use_memo(move || config_service.read().group(group_id).unwrap().clone());
-    let picked_hotkey = use_signal(|| group().hotkey);
+    // the page lives on for a moment when its group is removed
+    let group = use_memo(move || config_service.read().group(group_id).cloned());
+
+    rsx! {
+        if let Some(group) = group() {
+            GroupPage { config_service, group }
+        }
+    }
+}
+
+#[component]
+fn GroupPage(config_service: Signal<ConfigService>, group: Group) -> Element {
+    let group_id = group.id();
+    let picked_hotkey = use_signal(|| group.shortcut.hotkey);
     use_effect(move || {
-        config_service.write().set_hotkey(group_id, picked_hotkey());
+        config_service
+            .write()
+            .set_hotkey(Action::OpenGroup { group_id }, picked_hotkey());
     });
-    let name = use_signal(|| group().name.clone());
+    let trigger = use_signal(|| group.shortcut.trigger);
+    let mut trigger_conflict = use_signal(|| None::<String>);
+    use_effect(move || {
+        let conflict = config_service
+            .write()
+            .set_trigger(Action::OpenGroup { group_id }, trigger());
+        trigger_conflict.set(conflict.map(|a| config_service.peek().config().describe(&a)));
+    });
+    let context = use_signal(|| group.shortcut.context.clone());
+    use_effect(move || {
+        config_service
+            .write()
+            .set_context(Action::OpenGroup { group_id }, context());
+    });
+    let picked_chord = use_signal(|| group.shortcut.chord.clone());
+    let mut chord_conflict = use_signal(|| None::<String>);
+    use_effect(move || {
+        let conflict = config_service
+            .write()
+            .set_chord(Action::OpenGroup { group_id }, picked_chord());
+        chord_conflict.set(conflict.map(|a| config_service.peek().config().describe(&a)));
+    });
+    let name = use_signal(|| group.name.clone());
     use_effect(move || config_service.write().set_name(group_id, name()));
+    let mut enabled = use_signal(|| group.enabled);
+    use_effect(move || {
+        config_service
+            .write()
+            .set_group_enabled(group_id, enabled())
+    });
+    let mut mode = use_signal(|| group.mode);
+    use_effect(move || config_service.write().set_mode(group_id, mode()));
+    let has_children = use_memo(move || config_service.read().children(group_id).next().is_some());
+    let mut include_children = use_signal(|| group.include_children);
+    use_effect(move || {
+        config_service
+            .write()
+            .set_include_children(group_id, include_children());
+    });
+    let window_policy = use_signal(|| group.window_policy.clone());
+    use_effect(move || {
+        config_service
+            .write()
+            .set_window_policy(group_id, window_policy());
+    });
+    let pending = use_signal(|| None::<PendingMember>);
+    let picking_group = use_signal(|| false);
     use_app_list_listener(config_service, group_id);
+    let selected_members = use_signal(HashSet::<String>::new);
+    let active_app = match selected_members().len() {
+        1 => selected_members()
+            .iter()
+            .next()
+            .filter(|id| group.member(id).is_some_and(|m| m.as_app().is_some()))
+            .cloned(),
+        _ => None,
+    };
 
     rsx! {
         div {
             class: "flex flex-col gap-2",
             EditableText { text: name }
-            HotkeyPicker { picked_hotkey }
-            AppList { apps: group().apps().to_vec() }
+            label {
+                class: "flex items-center gap-2 text-sm",
+                input {
+                    class: "toggle toggle-sm",
+                    r#type: "checkbox",
+                    checked: enabled(),
+                    onchange: move |evt| enabled.set(evt.checked()),
+                }
+                "Enabled"
+            }
+            div {
+                class: "flex gap-2",
+                HotkeyPicker { picked_hotkey }
+                TriggerPicker { trigger }
+                select {
+                    class: "select select-sm",
+                    onchange: move |evt| {
+                        let picked = evt.value();
+                        let modes = GroupMode::all();
+                        if let Some(m) = modes.into_iter().find(|m| m.to_string() == picked) {
+                            mode.set(m);
+                        }
+                    },
+                    for option_mode in GroupMode::all() {
+                        option {
+                            value: "{option_mode}",
+                            selected: option_mode == mode(),
+                            "{option_mode}"
+                        }
+                    }
+                }
+            }
+            HotkeyStatus { config_service, action: Action::OpenGroup { group_id } }
+            if let Some(conflict) = trigger_conflict() {
+                span { class: "text-sm text-error", "Used by '{conflict}' with this trigger" }
+            }
+            ChordPicker { picked_chord }
+            if let Some(conflict) = chord_conflict() {
+                span { class: "text-sm text-error", "Can't be told apart from '{conflict}'" }
+            }
+            ContextPicker { context }
+            WindowPolicyPicker { window_policy }
+            if has_children() {
+                label {
+                    class: "flex items-center gap-2 text-sm",
+                    input {
+                        class: "toggle toggle-sm",
+                        r#type: "checkbox",
+                        checked: include_children(),
+                        onchange: move |evt| include_children.set(evt.checked()),
+                    }
+                    "Cycle through the apps of nested groups too"
+                }
+            }
+            RuleConfig { config_service, group_id }
+            AppList {
+                config_service,
+                group: group.clone(),
+                selected: selected_members,
+                on_pin: move |member_id: String| {
+                    config_service.write().pin_member(group_id, &member_id)
+                },
+                on_exclude: move |member_id: String| {
+                    config_service.write().exclude_member(group_id, &member_id)
+                },
+                on_flags: move |(member_id, flags): (String, MemberFlags)| {
+                    config_service.write().set_member_flags(group_id, &member_id, flags)
+                },
+                on_primary: move |member_id| {
+                    config_service.write().set_primary_member(group_id, member_id)
+                },
+                on_add: move |kind| {
+                    spawn(add_member(config_service, group_id, pending, picking_group, kind));
+                },
+            }
+            if picking_group() {
+                GroupPicker { config_service, group_id, picking_group }
+            } else if let Some(member) = pending() {
+                MemberDraft { config_service, group_id, member, pending }
+            }
+            if let Some(app_id) = active_app {
+                AppEntryConfig {
+                    key: "{app_id}",
+                    config_service,
+                    group_id,
+                    app_id: app_id.clone()
+                }
+            }
+            FocusConfig { config_service, group_id }
+            LaunchConfig { config_service, group_id }
+        }
+    }
+}
+
+/// Members whose text has to be typed in before they can be added
+#[derive(Clone, PartialEq)]
+enum PendingMember {
+    Url,
+    Command,
+    Window(App),
+    Matcher,
+}
+
+#[component]
+fn MemberDraft(
+    mut config_service: Signal<ConfigService>,
+    group_id: Uuid,
+    member: PendingMember,
+    mut pending: Signal<Option<PendingMember>>,
+) -> Element {
+    let mut draft = use_signal(String::new);
+    let mut is_regex = use_signal(|| false);
+    let to_member = {
+        let member = member.clone();
+        move |text: String| match &member {
+            PendingMember::Url => Member::Url(text),
+            PendingMember::Command => Member::Command(text),
+            PendingMember::Window(app) => Member::Window {
+                app: app.clone(),
+                pattern: match is_regex() {
+                    true => TitlePattern::regex(text),
+                    false => TitlePattern::Substring(text),
+                },
+            },
+            PendingMember::Matcher => Member::Matcher(match is_regex() {
+                true => AppMatcher::regex(text),
+                false => AppMatcher::glob(text),
+            }),
+        }
+    };
+    let is_valid = {
+        let to_member = to_member.clone();
+        move || match to_member(draft().trim().to_string()) {
+            Member::Window { pattern, .. } => pattern.is_valid(),
+            Member::Matcher(matcher) => matcher.is_valid(),
+            _ => !draft().trim().is_empty(),
+        }
+    };
+    // the installed apps are scanned off-thread, the preview fills in once they are known
+    let preview: Vec<App> = match to_member(draft().trim().to_string()) {
+        Member::Matcher(matcher) if matcher.is_valid() => config_service
+            .read()
+            .config()
+            .installed_apps()
+            .iter()
+            .map(|(app, _)| app)
+            .filter(|app| matcher.matches(&app.id()))
+            .cloned()
+            .collect(),
+        _ => Vec::new(),
+    };
+    let onkeydown = {
+        let is_valid = is_valid.clone();
+        let to_member = to_member.clone();
+        move |evt: KeyboardEvent| {
+            match evt.key() {
+                Key::Enter if is_valid() => {
+                    let member = to_member(draft().trim().to_string());
+                    config_service.write().add_member(group_id, member);
+                }
+                Key::Escape => {}
+                _ => return,
+            }
+            pending.set(None);
+        }
+    };
+    let placeholder = match &member {
+        PendingMember::Url => "https://...".to_string(),
+        PendingMember::Command => "Shell command".to_string(),
+        PendingMember::Window(app) => format!("Title of the {app} windows"),
+        PendingMember::Matcher => "App id, e.g. com.jetbrains.*".to_string(),
+    };
+    let error_class = match draft().is_empty() || is_valid() {
+        true => "",
+        false => "input-error",
+    };
+
+    rsx! {
+        div {
+            class: "flex gap-2 items-center",
+            input {
+                class: "input input-sm w-full {error_class}",
+                placeholder,
+                value: "{draft}",
+                autofocus: true,
+                oninput: move |evt| draft.set(evt.value()),
+                onkeydown,
+            }
+            if matches!(member, PendingMember::Window(_) | PendingMember::Matcher) {
+                label {
+                    class: "label text-sm",
+                    input {
+                        r#type: "checkbox",
+                        class: "checkbox checkbox-sm",
+                        checked: is_regex(),
+                        onchange: move |evt| is_regex.set(evt.checked()),
+                    }
+                    "Regex"
+                }
+            }
+        }
+        if member == PendingMember::Matcher && !draft().is_empty() {
+            div {
+                class: "text-sm opacity-70",
+                "Currently matches {preview.len()} apps"
+                for app in preview.iter().take(PREVIEW_LIMIT) {
+                    div { key: "{app.id()}", class: "truncate", "{app.id()}" }
+                }
+            }
+        }
+    }
+}
+
+/// Lists the groups that can be included without including this one again
+#[component]
+fn GroupPicker(
+    mut config_service: Signal<ConfigService>,
+    group_id: Uuid,
+    mut picking_group: Signal<bool>,
+) -> Element {
+    let candidates: Vec<(Uuid, String)> = config_service
+        .read()
+        .groups()
+        .iter()
+        .filter(|g| config_service.read().can_include(group_id, g.id()))
+        .map(|g| (g.id(), g.name.clone()))
+        .collect();
+    let options = candidates.clone();
+    let include = move |evt: Event<FormData>| {
+        let picked = evt.value();
+        let candidate = candidates.iter().find(|(id, _)| id.to_string() == picked);
+        if let Some((other_id, _)) = candidate.cloned() {
+            let member = Member::Group { group_id: other_id };
+            config_service.write().add_member(group_id, member);
+        }
+        picking_group.set(false);
+    };
+
+    rsx! {
+        select {
+            class: "select select-sm w-full",
+            onchange: include,
+            option { value: "", selected: true, disabled: true, "Include group..." }
+            for (id, name) in options {
+                option { value: "{id}", "{name}" }
+            }
         }
     }
 }
@@ -32,28 +359,86 @@ fn use_app_list_listener(config_service: Signal<ConfigService>, group_id: Uuid)
     let app_list_listener = use_coroutine(
         move |mut receiver: UnboundedReceiver<ListOperation<String>>| async move {
             while let Some(list_operation) = receiver.next().await {
-                do_app_list_operation(config_service, group_id, list_operation).await;
+                do_app_list_operation(config_service, group_id, list_operation);
             }
         },
     );
     use_context_provider(|| app_list_listener.tx()); // used in the (generic) list
 }
 
-async fn do_app_list_operation(
+fn do_app_list_operation(
     mut config_service: Signal<ConfigService>,
     group_id: Uuid,
     list_operation: ListOperation<String>,
 ) {
     match list_operation {
-        ListOperation::Add => {
-            if let Ok(Some(app)) = AppDialog::select_app().await {
-                config_service.write().add_app(group_id, app)
+        ListOperation::Add => {} // the member list asks for a kind first, see `add_member`
+        ListOperation::Remove(members) => {
+            for member_id in members {
+                config_service.write().remove_member(group_id, member_id);
             }
         }
-        ListOperation::Remove(apps) => {
-            for app_id in apps {
-                config_service.write().remove_app(group_id, app_id);
+        ListOperation::InsertBefore(member_id, before_id) => {
+            config_service
+                .write()
+                .move_member(group_id, &member_id, before_id.as_deref());
+        }
+        ListOperation::Reparent(..) => {} // members aren't nested
+    }
+}
+
+async fn add_member(
+    mut config_service: Signal<ConfigService>,
+    group_id: Uuid,
+    mut pending: Signal<Option<PendingMember>>,
+    mut picking_group: Signal<bool>,
+    kind: MemberKind,
+) {
+    // only one draft or picker is shown at a time
+    pending.set(None);
+    picking_group.set(false);
+    let member = match kind {
+        MemberKind::App => pick_app().await.map(|app| Member::App(AppEntry::new(app))),
+        MemberKind::File => rfd::AsyncFileDialog::new()
+            .pick_file()
+            .await
+            .map(|f| Member::Path {
+                path: f.path().to_path_buf(),
+                is_dir: false,
+            }),
+        MemberKind::Folder => {
+            rfd::AsyncFileDialog::new()
+                .pick_folder()
+                .await
+                .map(|f| Member::Path {
+                    path: f.path().to_path_buf(),
+                    is_dir: true,
+                })
+        }
+        MemberKind::Window => {
+            if let Some(app) = pick_app().await {
+                pending.set(Some(PendingMember::Window(app)));
             }
+            None
+        }
+        MemberKind::Group => {
+            picking_group.set(true);
+            None
+        }
+        MemberKind::Matcher => {
+            pending.set(Some(PendingMember::Matcher));
+            None
+        }
+        MemberKind::Url => {
+            pending.set(Some(PendingMember::Url));
+            None
+        }
+        MemberKind::Command => {
+            pending.set(Some(PendingMember::Command));
+            None
         }
+    };
+    if let Some(member) = member {
+        config_service.write().add_member(group_id, member)
     }
 }
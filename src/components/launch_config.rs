@@ -0,0 +1,112 @@
+use std::time::Duration;
+
+use dioxus::prelude::*;
+use uuid::Uuid;
+
+use crate::components::util::{
+    ChordPicker, ContextPicker, HotkeyPicker, HotkeyStatus, TriggerPicker,
+};
+use crate::models::{Action, Identifiable};
+use crate::services::ConfigService;
+
+#[component]
+pub fn LaunchConfig(config_service: Signal<ConfigService>, group_id: Uuid) -> Element {
+    let group = use_memo(move || config_service.read().group(group_id).unwrap().clone());
+    let picked_hotkey = use_signal(|| group().launch_shortcut.hotkey);
+    use_effect(move || {
+        config_service
+            .write()
+            .set_hotkey(Action::LaunchGroup { group_id }, picked_hotkey());
+    });
+    let trigger = use_signal(|| group().launch_shortcut.trigger);
+    let mut trigger_conflict = use_signal(|| None::<String>);
+    use_effect(move || {
+        let conflict = config_service
+            .write()
+            .set_trigger(Action::LaunchGroup { group_id }, trigger());
+        trigger_conflict.set(conflict.map(|a| config_service.peek().config().describe(&a)));
+    });
+    let picked_chord = use_signal(|| group().launch_shortcut.chord.clone());
+    let mut chord_conflict = use_signal(|| None::<String>);
+    use_effect(move || {
+        let conflict = config_service
+            .write()
+            .set_chord(Action::LaunchGroup { group_id }, picked_chord());
+        chord_conflict.set(conflict.map(|a| config_service.peek().config().describe(&a)));
+    });
+    let context = use_signal(|| group().launch_shortcut.context.clone());
+    use_effect(move || {
+        config_service
+            .write()
+            .set_context(Action::LaunchGroup { group_id }, context());
+    });
+    let mut stagger_ms = use_signal(|| group().launch_stagger.as_millis() as u64);
+    use_effect(move || {
+        config_service
+            .write()
+            .set_launch_stagger(group_id, Duration::from_millis(stagger_ms()));
+    });
+    // also set from the member list, so it is read from the group directly
+    let primary_member_id = use_memo(move || group().primary_member_id.clone());
+    let members: Vec<(String, String)> = group()
+        .all_members()
+        .map(|m| (m.id(), config_service.read().config().describe_member(m)))
+        .collect();
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-2",
+            span { class: "text-sm font-bold", "Launch all" }
+            div {
+                class: "flex gap-2",
+                HotkeyPicker { picked_hotkey }
+                TriggerPicker { trigger }
+            }
+            HotkeyStatus { config_service, action: Action::LaunchGroup { group_id } }
+            if let Some(conflict) = trigger_conflict() {
+                span { class: "text-sm text-error", "Used by '{conflict}' with this trigger" }
+            }
+            ChordPicker { picked_chord }
+            if let Some(conflict) = chord_conflict() {
+                span { class: "text-sm text-error", "Can't be told apart from '{conflict}'" }
+            }
+            ContextPicker { context }
+            label {
+                class: "flex items-center gap-2 text-sm",
+                "Stagger (ms)"
+                input {
+                    class: "input input-sm w-24",
+                    r#type: "number",
+                    min: "0",
+                    value: "{stagger_ms}",
+                    oninput: move |evt| {
+                        if let Ok(ms) = evt.value().parse() {
+                            stagger_ms.set(ms);
+                        }
+                    },
+                }
+            }
+            select {
+                class: "select select-sm",
+                onchange: move |evt| {
+                    let member_id = evt.value();
+                    config_service
+                        .write()
+                        .set_primary_member(group_id, (!member_id.is_empty()).then_some(member_id));
+                },
+                option {
+                    value: "",
+                    selected: primary_member_id().is_none(),
+                    "First member in front"
+                }
+                for (member_id, label) in members {
+                    option {
+                        value: "{member_id}",
+                        selected: primary_member_id() == Some(member_id.clone()),
+                        "{label} in front"
+                    }
+                }
+            }
+        }
+    }
+}
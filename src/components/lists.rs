@@ -1,9 +1,11 @@
 mod app_list;
+mod binding_list;
 mod group_list;
 mod list;
 mod list_menu;
 mod list_row;
 
 pub(super) use app_list::AppList;
+pub(super) use binding_list::BindingList;
 pub(super) use group_list::GroupList;
 pub(super) use list_menu::ListOperation;
@@ -3,25 +3,184 @@ use std::collections::HashSet;
 use dioxus::prelude::*;
 
 use super::list::{List, Renderable};
-use crate::models::Identifiable;
-use crate::os::App;
+use super::list_menu::ListOperation;
+use crate::models::{Group, Identifiable, Member, MemberFlags, MemberKind};
+use crate::services::ConfigService;
 
 #[component]
-pub fn AppList(apps: Vec<App>) -> Element {
+pub fn AppList(
+    config_service: Signal<ConfigService>,
+    group: Group,
+    selected: Signal<HashSet<String>>,
+    on_pin: EventHandler<String>,
+    on_exclude: EventHandler<String>,
+    on_flags: EventHandler<(String, MemberFlags)>,
+    on_primary: EventHandler<Option<String>>,
+    on_add: EventHandler<MemberKind>,
+) -> Element {
+    let rows: Vec<MemberRow> = group
+        .members()
+        .iter()
+        .map(|member| MemberRow {
+            member: member.clone(),
+            label: config_service.read().config().describe_member(member),
+            flags: group.flags(&member.id()),
+            is_primary: group.primary_member_id == Some(member.id()),
+            on_flags,
+            on_primary,
+        })
+        .collect();
+    // added by the group's rule, these can't be edited, only pinned or excluded
+    let computed = group.computed_members().clone();
+
     rsx! {
         div {
             List {
-                elements: apps,
-                selected: use_signal(HashSet::<String>::new),
+                elements: rows,
+                selected,
+                add_menu: render_add_menu(on_add),
+            }
+            for member in computed {
+                div {
+                    key: "{member.id()}",
+                    class: "flex items-center gap-2 px-3 text-sm opacity-70",
+                    span { class: "badge badge-sm badge-ghost", "Rule" }
+                    span { class: "grow truncate", "{member.id()}" }
+                    button {
+                        class: "btn btn-xs btn-ghost",
+                        onclick: {
+                            let member_id = member.id();
+                            move |_| on_pin.call(member_id.clone())
+                        },
+                        "Pin"
+                    }
+                    button {
+                        class: "btn btn-xs btn-ghost",
+                        onclick: {
+                            let member_id = member.id();
+                            move |_| on_exclude.call(member_id.clone())
+                        },
+                        "Exclude"
+                    }
+                }
             }
         }
     }
 }
 
-impl Renderable<String> for App {
+/// Members come in several kinds, so adding asks which one first
+fn render_add_menu(on_add: EventHandler<MemberKind>) -> Element {
+    rsx! {
+        div {
+            class: "dropdown",
+            div {
+                class: "btn btn-xs",
+                tabindex: 0,
+                role: "button",
+                "Add"
+            }
+            ul {
+                class: "dropdown-content menu bg-base-100 rounded-box z-10 shadow-sm",
+                tabindex: 0,
+                for kind in MemberKind::all() {
+                    li {
+                        a {
+                            onclick: move |_| on_add.call(kind),
+                            "{kind}"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A member along with its flags, which can be toggled right in the list
+#[derive(Clone, PartialEq)]
+struct MemberRow {
+    member: Member,
+    label: String, // included groups go by their current name
+    flags: MemberFlags,
+    is_primary: bool,
+    on_flags: EventHandler<(String, MemberFlags)>,
+    on_primary: EventHandler<Option<String>>,
+}
+
+impl Identifiable<String> for MemberRow {
+    fn id(&self) -> String {
+        self.member.id()
+    }
+}
+
+impl Renderable<String> for MemberRow {
     fn render(&self) -> Element {
+        let flags = self.flags;
+        let toggle = |flags: MemberFlags| {
+            let (member_id, on_flags) = (self.id(), self.on_flags);
+            move |evt: Event<MouseData>| {
+                evt.stop_propagation(); // don't select the row
+                on_flags.call((member_id.clone(), flags))
+            }
+        };
+        let toggle_cycle = toggle(MemberFlags {
+            in_cycle: !flags.in_cycle,
+            ..flags
+        });
+        let toggle_launch = toggle(MemberFlags {
+            in_launch_all: !flags.in_launch_all,
+            ..flags
+        });
+        let (member_id, is_primary, on_primary) = (self.id(), self.is_primary, self.on_primary);
+        let toggle_primary = move |evt: Event<MouseData>| {
+            evt.stop_propagation();
+            on_primary.call((!is_primary).then(|| member_id.clone()))
+        };
+        let opacity = |on: bool| if on { "" } else { "opacity-30" };
+
         rsx! {
-            span { "{self.id()}" }
+            span {
+                class: "grow flex gap-2 items-center",
+                { render_member(&self.member, &self.label) }
+            }
+            span {
+                class: "badge badge-xs {opacity(flags.in_cycle)}",
+                title: "Reached by the group hotkey",
+                onclick: toggle_cycle,
+                "Cycle"
+            }
+            span {
+                class: "badge badge-xs {opacity(flags.in_launch_all)}",
+                title: "Opened by launch all",
+                onclick: toggle_launch,
+                "Launch"
+            }
+            span {
+                class: "badge badge-xs {opacity(self.is_primary)}",
+                title: "Opened first",
+                onclick: toggle_primary,
+                "Primary"
+            }
+        }
+    }
+
+    fn dropped(member_id: String, before_id: Option<String>) -> Option<ListOperation<String>> {
+        Some(ListOperation::InsertBefore(member_id, before_id))
+    }
+}
+
+fn render_member(member: &Member, label: &str) -> Element {
+    rsx! {
+        if let Some(entry) = member.as_app() {
+            span { "{member.id()}" }
+            if !entry.launch_options.is_empty() {
+                span { class: "opacity-50", "(custom launch)" }
+            }
+        } else if let Member::Group { .. } = member {
+            span { class: "badge badge-sm badge-ghost", "{member.kind()}" }
+            span { "{label}" }
+        } else {
+            span { class: "badge badge-sm badge-ghost", "{member.kind()}" }
+            span { "{member.id()}" }
         }
     }
 }
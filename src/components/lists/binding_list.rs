@@ -0,0 +1,77 @@
+use std::collections::HashSet;
+
+use dioxus::prelude::*;
+use futures_util::StreamExt;
+use uuid::Uuid;
+
+use super::list::{List, Renderable};
+use super::list_menu::ListOperation;
+use crate::models::Binding;
+use crate::services::ConfigService;
+
+#[component]
+pub fn BindingList(
+    config_service: Signal<ConfigService>,
+    selected: Signal<HashSet<Uuid>>,
+) -> Element {
+    use_binding_list_listener(config_service, selected);
+
+    rsx! {
+        List {
+            elements: config_service.read().standalone_bindings().clone(),
+            selected,
+        }
+    }
+}
+
+impl Renderable<Uuid> for Binding {
+    fn render(&self) -> Element {
+        rsx! {
+            span {
+                class: if !self.enabled { "opacity-50" },
+                "{self.name}"
+            }
+            if let Some(hotkey) = self.shortcut.hotkey {
+                span { class: "opacity-50", "{hotkey}" }
+            }
+        }
+    }
+}
+
+// provided here rather than in Root, the group list sends the same kind of operations
+fn use_binding_list_listener(
+    config_service: Signal<ConfigService>,
+    selected: Signal<HashSet<Uuid>>,
+) {
+    let listener = use_coroutine(
+        move |mut receiver: UnboundedReceiver<ListOperation<Uuid>>| async move {
+            while let Some(list_operation) = receiver.next().await {
+                do_binding_list_operation(config_service, selected, list_operation)
+            }
+        },
+    );
+    use_context_provider(|| listener.tx()); // used in the (generic) list
+}
+
+fn do_binding_list_operation(
+    mut config_service: Signal<ConfigService>,
+    mut selected: Signal<HashSet<Uuid>>,
+    list_operation: ListOperation<Uuid>,
+) {
+    let mut cs = config_service.write();
+    match list_operation {
+        ListOperation::Add => {
+            let binding_id = cs.add_binding("New Binding".to_string());
+            let mut sel = selected.write();
+            sel.clear();
+            sel.insert(binding_id);
+        }
+        ListOperation::Remove(bindings) => {
+            for binding_id in bindings {
+                cs.remove_binding(binding_id)
+            }
+        }
+        // bindings are neither nested nor reordered
+        ListOperation::Reparent(..) | ListOperation::InsertBefore(..) => {}
+    }
+}
@@ -4,22 +4,62 @@ use dioxus::prelude::*;
 use uuid::Uuid;
 
 use super::list::{List, Renderable};
-use crate::models::Group;
+use super::list_menu::ListOperation;
+use crate::models::{Group, Identifiable};
 
 #[component]
-pub fn GroupList(groups: Vec<Group>, selected: Signal<HashSet<Uuid>>) -> Element {
+pub fn GroupList(
+    groups: Vec<Group>,
+    selected: Signal<HashSet<Uuid>>,
+    /// The group the frontmost app belongs to
+    current: Option<Uuid>,
+) -> Element {
+    let rows: Vec<GroupRow> = groups
+        .into_iter()
+        .map(|group| GroupRow {
+            is_current: current == Some(group.id()),
+            group,
+        })
+        .collect();
+
     rsx! {
         List {
-            elements: groups,
+            elements: rows,
             selected,
         }
     }
 }
 
-impl Renderable<Uuid> for Group {
+#[derive(Clone, PartialEq)]
+struct GroupRow {
+    group: Group,
+    is_current: bool,
+}
+
+impl Identifiable<Uuid> for GroupRow {
+    fn id(&self) -> Uuid {
+        self.group.id()
+    }
+}
+
+impl Renderable<Uuid> for GroupRow {
     fn render(&self) -> Element {
         rsx! {
-            span { "{self.name}" }
+            span {
+                class: if !self.group.enabled { "opacity-50" },
+                "{self.group.name}"
+            }
+            if self.is_current {
+                span { class: "badge badge-xs badge-primary", title: "Active", "●" }
+            }
         }
     }
+
+    fn parent_id(&self) -> Option<Uuid> {
+        self.group.parent_id
+    }
+
+    fn dropped(group_id: Uuid, parent_id: Option<Uuid>) -> Option<ListOperation<Uuid>> {
+        Some(ListOperation::Reparent(group_id, parent_id))
+    }
 }
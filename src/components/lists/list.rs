@@ -3,24 +3,99 @@ use std::hash::Hash;
 
 use dioxus::prelude::*;
 
-use crate::components::lists::list_menu::ListMenu;
+use crate::components::lists::list_menu::{ListMenu, ListOperation};
 use crate::components::lists::list_row::ListRow;
 use crate::models::Identifiable;
 
 #[component]
-pub(super) fn List<E, I>(elements: Vec<E>, selected: Signal<HashSet<I>>) -> Element
+pub(super) fn List<E, I>(
+    elements: Vec<E>,
+    selected: Signal<HashSet<I>>,
+    add_menu: Option<Element>,
+) -> Element
 where
     I: Clone + Eq + Hash + 'static,
     E: Renderable<I> + Clone + PartialEq + 'static,
 {
+    let collapsed = use_signal(HashSet::<I>::new);
+    let mut dragged = use_signal(|| None::<I>);
+    let sender = use_context::<UnboundedSender<ListOperation<I>>>();
+    let rows = tree_rows(&elements, &collapsed.read());
+    let move_to_top = move |evt: DragEvent| {
+        evt.prevent_default();
+        if let Some(element_id) = dragged.take()
+            && let Some(operation) = E::dropped(element_id, None)
+        {
+            let _ = sender.unbounded_send(operation);
+        }
+    };
+
     rsx! {
-        ListMenu { selected }
-        for element in elements {
-            ListRow { element, selected }
+        ListMenu { selected, add_menu }
+        for (element, depth, has_children) in rows {
+            ListRow { element, selected, depth, has_children, collapsed, dragged }
+        }
+        // dropping below the rows moves back to the top level, or to the end
+        div {
+            class: "min-h-4 grow",
+            ondragover: move |evt| evt.prevent_default(),
+            ondrop: move_to_top,
         }
     }
 }
 
 pub(super) trait Renderable<I: Clone + Eq + Hash>: Identifiable<I> {
     fn render(&self) -> Element;
+
+    /// Elements with a parent are rendered nested below it
+    fn parent_id(&self) -> Option<I> {
+        None
+    }
+
+    /// What dropping an element onto another one, or below all of them, does
+    fn dropped(_dragged_id: I, _target_id: Option<I>) -> Option<ListOperation<I>> {
+        None
+    }
+}
+
+/// Flattens the tree depth first, with the depth of each row and whether it has children.
+/// Elements whose parent isn't in the list are shown at the top level.
+fn tree_rows<E, I>(elements: &[E], collapsed: &HashSet<I>) -> Vec<(E, usize, bool)>
+where
+    I: Clone + Eq + Hash,
+    E: Renderable<I> + Clone,
+{
+    fn visit<E, I>(
+        element: &E,
+        depth: usize,
+        elements: &[E],
+        collapsed: &HashSet<I>,
+        rows: &mut Vec<(E, usize, bool)>,
+    ) where
+        I: Clone + Eq + Hash,
+        E: Renderable<I> + Clone,
+    {
+        let id = element.id();
+        let children: Vec<&E> = elements
+            .iter()
+            .filter(|e| e.parent_id().as_ref() == Some(&id))
+            .collect();
+        rows.push((element.clone(), depth, !children.is_empty()));
+        if collapsed.contains(&id) {
+            return;
+        }
+        for child in children {
+            visit(child, depth + 1, elements, collapsed, rows);
+        }
+    }
+
+    let ids: HashSet<I> = elements.iter().map(|e| e.id()).collect();
+    let mut rows = Vec::new();
+    for root in elements
+        .iter()
+        .filter(|e| e.parent_id().is_none_or(|p| !ids.contains(&p)))
+    {
+        visit(root, 0, elements, collapsed, &mut rows);
+    }
+    rows
 }
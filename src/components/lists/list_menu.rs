@@ -10,10 +10,13 @@ where
 {
     Add, // adding is interactive
     Remove(HashSet<I>),
+    Reparent(I, Option<I>), // nests an element below another one, or back at the top
+    InsertBefore(I, Option<I>), // moves an element before another one, or to the end
 }
 
+/// Lists that add more than one kind of element bring their own `add_menu`
 #[component]
-pub(super) fn ListMenu<I>(selected: Signal<HashSet<I>>) -> Element
+pub(super) fn ListMenu<I>(selected: Signal<HashSet<I>>, add_menu: Option<Element>) -> Element
 where
     I: Clone + Eq + Hash + 'static,
 {
@@ -31,10 +34,14 @@ where
     rsx! {
         div {
             class: "flex",
-            button {
-                class: "btn btn-xs",
-                onclick: add,
-                "Add"
+            if let Some(add_menu) = add_menu {
+                { add_menu }
+            } else {
+                button {
+                    class: "btn btn-xs",
+                    onclick: add,
+                    "Add"
+                }
             }
             button {
                 class: "btn btn-xs",
@@ -4,14 +4,23 @@ use std::hash::Hash;
 use dioxus::prelude::*;
 
 use super::list::Renderable;
+use super::list_menu::ListOperation;
 use crate::os::{Keyboard, KeyboardBehavior};
 
 #[component]
-pub(super) fn ListRow<E, I>(element: E, mut selected: Signal<HashSet<I>>) -> Element
+pub(super) fn ListRow<E, I>(
+    element: E,
+    mut selected: Signal<HashSet<I>>,
+    #[props(default)] depth: usize,
+    #[props(default)] has_children: bool,
+    mut collapsed: Signal<HashSet<I>>,
+    mut dragged: Signal<Option<I>>,
+) -> Element
 where
     I: Clone + Eq + Hash + 'static,
     E: Renderable<I> + Clone + PartialEq + 'static,
 {
+    let sender = use_context::<UnboundedSender<ListOperation<I>>>();
     let my_element_id = element.id();
     let is_selected = use_memo(move || selected().contains(&my_element_id));
     let element_id = element.id();
@@ -28,6 +37,28 @@ where
             sel.insert(element_id.clone());
         }
     };
+    let element_id = element.id();
+    let is_collapsed = collapsed.read().contains(&element_id);
+    let toggle_collapsed = move |evt: Event<MouseData>| {
+        evt.stop_propagation(); // don't select the row
+        let mut col = collapsed.write();
+        if !col.remove(&element_id) {
+            col.insert(element_id.clone());
+        }
+    };
+    let element_id = element.id();
+    let start_drag = move |_| dragged.set(Some(element_id.clone()));
+    let element_id = element.id();
+    let drop_onto = move |evt: DragEvent| {
+        evt.prevent_default();
+        evt.stop_propagation();
+        if let Some(dragged_id) = dragged.take()
+            && dragged_id != element_id
+            && let Some(operation) = E::dropped(dragged_id, Some(element_id.clone()))
+        {
+            let _ = sender.unbounded_send(operation);
+        }
+    };
 
     rsx! {
         button {
@@ -36,7 +67,19 @@ where
                 "btn btn-sm w-full justify-start {}",
                 if is_selected() { "btn-active" } else { "btn-ghost" }
             ),
+            style: "padding-left: {0.75 + depth as f32}rem",
+            draggable: true,
             onclick: toggle_active,
+            ondragstart: start_drag,
+            ondragover: move |evt| evt.prevent_default(),
+            ondrop: drop_onto,
+            if has_children {
+                span {
+                    class: "opacity-50",
+                    onclick: toggle_collapsed,
+                    if is_collapsed { "▸" } else { "▾" }
+                }
+            }
             { element.render() }
         }
     }
@@ -0,0 +1,44 @@
+use dioxus::prelude::*;
+
+use crate::services::{ActionOutcome, OutcomeHistory};
+
+/// Lists what the recent actions did, newest first
+#[component]
+pub fn OutcomeLog(history: Signal<OutcomeHistory>) -> Element {
+    let reports: Vec<(u64, String, &'static str)> = history
+        .read()
+        .recent()
+        .map(|r| {
+            (
+                r.at.elapsed().as_secs(),
+                r.to_string(),
+                alert_class(&r.outcome),
+            )
+        })
+        .collect();
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-2",
+            span { class: "text-sm font-bold", "History" }
+            for (age, report, alert) in reports.iter() {
+                div {
+                    class: "alert alert-soft {alert}",
+                    span { class: "whitespace-pre-line", "{report} ({age}s ago)" }
+                }
+            }
+            if reports.is_empty() {
+                span { class: "text-sm", "No actions were run yet" }
+            }
+        }
+    }
+}
+
+pub fn alert_class(outcome: &ActionOutcome) -> &'static str {
+    match outcome {
+        ActionOutcome::Success => "alert-success",
+        ActionOutcome::Skipped { .. } => "alert-info",
+        ActionOutcome::Partial { .. } => "alert-warning",
+        ActionOutcome::Failed { .. } => "alert-error",
+    }
+}
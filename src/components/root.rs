@@ -1,13 +1,22 @@
 use std::collections::HashSet;
+use std::time::{Duration, Instant};
 
 use dioxus::prelude::*;
 use futures_util::StreamExt;
+use log::warn;
 use uuid::Uuid;
 
+use crate::components::binding_config::BindingConfig;
 use crate::components::group_config::GroupConfig;
-use crate::components::lists::{GroupList, ListOperation};
+use crate::components::lists::{BindingList, GroupList, ListOperation};
+use crate::components::outcome_log::{OutcomeLog, alert_class};
+use crate::components::settings_config::SettingsConfig;
 use crate::models::Action;
-use crate::services::{ActionService, ConfigService, SharedSender};
+use crate::services::{ActionReport, ActionService, ConfigService, OutcomeHistory, SharedSender};
+
+const CURRENT_GROUP_POLL: Duration = Duration::from_secs(1);
+const TOAST_DURATION: Duration = Duration::from_secs(4);
+const TOAST_LIMIT: usize = 3;
 
 #[component]
 pub fn Root() -> Element {
@@ -15,13 +24,45 @@ pub fn Root() -> Element {
     let action_sender = use_hook(SharedSender::new);
     let config_service =
         use_signal(|| ConfigService::new(registered_record_sender.clone(), action_sender.clone()));
+    // rules and matchers use the installed apps once they are scanned
+    use_future(move || async move {
+        let installed_apps = ConfigService::scan_installed_apps().await;
+        let mut config_service = config_service;
+        config_service.write().set_installed_apps(installed_apps);
+    });
+    let current_group = use_signal(|| None::<Uuid>);
+    let history = use_signal(OutcomeHistory::default);
+    let toasts = use_signal(Vec::<ActionReport>::new);
+    let outcome_sender = use_outcome_listener(history, toasts);
     // We inject the action sender like this to bypass the cyclic dependency with config service
-    action_sender.set(Some(use_action_listener(config_service)));
+    action_sender.set(Some(use_action_listener(
+        config_service,
+        current_group,
+        outcome_sender,
+    )));
     use_context_provider(|| registered_record_sender);
     use_context_provider(|| action_sender);
 
-    let selected = use_signal(HashSet::<Uuid>::new);
+    let mut selected = use_signal(HashSet::<Uuid>::new);
     use_group_list_listener(config_service, selected);
+    let mut selected_bindings = use_signal(HashSet::<Uuid>::new);
+    let mut show_settings = use_signal(|| false);
+    let mut show_history = use_signal(|| false);
+    // only one page at a time: a group, a binding, the settings or the history
+    use_effect(move || {
+        if !selected().is_empty() {
+            show_settings.set(false);
+            show_history.set(false);
+            selected_bindings.write().clear();
+        }
+    });
+    use_effect(move || {
+        if !selected_bindings().is_empty() {
+            show_settings.set(false);
+            show_history.set(false);
+            selected.write().clear();
+        }
+    });
     let active_group = use_memo(move || {
         if selected().len() == 1 {
             selected().iter().next().copied()
@@ -29,16 +70,58 @@ pub fn Root() -> Element {
             None
         }
     });
+    let active_binding = use_memo(move || {
+        if selected_bindings().len() == 1 {
+            selected_bindings().iter().next().copied()
+        } else {
+            None
+        }
+    });
 
     rsx! {
         { render_stylesheet() }
         div {
             class: "flex h-screen",
             aside {
-                class: "flex-1 p-2 border-r",
-                GroupList {
-                    groups: config_service.read().groups().clone(),
-                    selected
+                class: "flex flex-1 flex-col p-2 border-r",
+                div {
+                    class: "flex-1",
+                    GroupList {
+                        groups: config_service.read().groups().clone(),
+                        selected,
+                        current: current_group(),
+                    }
+                    span { class: "text-sm font-bold", "Bindings" }
+                    BindingList { config_service, selected: selected_bindings }
+                }
+                if config_service.read().settings().hotkeys_paused {
+                    span { class: "badge badge-warning mb-2", "Hotkeys paused" }
+                }
+                button {
+                    class: format_args!(
+                        "btn btn-sm w-full justify-start {}",
+                        if show_settings() { "btn-active" } else { "btn-ghost" }
+                    ),
+                    onclick: move |_| {
+                        selected.write().clear();
+                        selected_bindings.write().clear();
+                        show_history.set(false);
+                        show_settings.set(true);
+                    },
+                    "Settings"
+                }
+                button {
+                    class: format_args!(
+                        "btn btn-sm w-full justify-start {}",
+                        if show_history() { "btn-active" } else { "btn-ghost" }
+                    ),
+                    onclick: move |_| {
+                        selected.write().clear();
+                        selected_bindings.write().clear();
+                        show_settings.set(false);
+                        show_history.set(true);
+                    },
+                    "History"
                 }
             }
             main {
@@ -49,6 +132,25 @@ pub fn Root() -> Element {
                         config_service,
                         group_id
                     }
+                } else if let Some(binding_id) = active_binding() {
+                    BindingConfig {
+                        key: "{binding_id}",
+                        config_service,
+                        binding_id
+                    }
+                } else if show_settings() {
+                    SettingsConfig { config_service }
+                } else if show_history() {
+                    OutcomeLog { history }
+                }
+            }
+        }
+        div {
+            class: "toast toast-end",
+            for report in toasts() {
+                div {
+                    class: format_args!("alert {}", alert_class(&report.outcome)),
+                    span { class: "whitespace-pre-line", "{report}" }
                 }
             }
         }
@@ -71,16 +173,113 @@ fn render_stylesheet() -> Element {
     }
 }
 
-fn use_action_listener(config_service: Signal<ConfigService>) -> UnboundedSender<Action> {
+fn use_action_listener(
+    config_service: Signal<ConfigService>,
+    current_group: Signal<Option<Uuid>>,
+    outcome_sender: UnboundedSender<ActionReport>,
+) -> UnboundedSender<Action> {
     let listener = use_coroutine(move |mut receiver: UnboundedReceiver<Action>| async move {
-        let mut action_service = ActionService::default();
-        while let Some(action) = receiver.next().await {
-            action_service.execute(&config_service.read(), &action)
+        let action_service = ActionService::new(move || {
+            let mut config_service = config_service;
+            config_service.write().toggle_hotkeys_paused();
+        });
+        loop {
+            // the frontmost app also changes without our hotkeys, so check it now and then
+            let wait = match config_service.read().key_deadline() {
+                Some(deadline) => {
+                    let remaining = deadline.saturating_duration_since(Instant::now());
+                    remaining.min(CURRENT_GROUP_POLL)
+                }
+                None => CURRENT_GROUP_POLL,
+            };
+            // key events only lead to an action once a chord or trigger is complete
+            let actions: Vec<Action> = tokio::select! {
+                action = receiver.next() => match action {
+                    Some(action) => {
+                        let resolved = config_service.write().resolve_action(action);
+                        resolved.into_iter().collect()
+                    }
+                    None => break,
+                },
+                _ = tokio::time::sleep(wait) => config_service.write().expire_keys(),
+            };
+            // actions may wait for a delay, which must not hold up the hotkeys
+            for action in actions {
+                spawn(run_action(
+                    action_service.clone(),
+                    config_service,
+                    current_group,
+                    action,
+                    outcome_sender.clone(),
+                ));
+            }
+            update_current_group(&action_service, config_service, current_group);
+            // hotkeys limited to some apps are only registered in front of them
+            let frontmost = config_service.read().frontmost_change();
+            if let Some(frontmost) = frontmost {
+                config_service.write().set_frontmost(frontmost);
+            }
         }
     });
     listener.tx()
 }
 
+async fn run_action(
+    action_service: ActionService,
+    config_service: Signal<ConfigService>,
+    current_group: Signal<Option<Uuid>>,
+    action: Action,
+    outcome_sender: UnboundedSender<ActionReport>,
+) {
+    // a snapshot, the config must not stay borrowed while the action awaits
+    let config = config_service.read().snapshot();
+    let report = action_service.execute(&config, &action).await;
+    let _ = outcome_sender.unbounded_send(report);
+    update_current_group(&action_service, config_service, current_group);
+}
+
+fn update_current_group(
+    action_service: &ActionService,
+    config_service: Signal<ConfigService>,
+    mut current_group: Signal<Option<Uuid>>,
+) {
+    let current = action_service.current_group(config_service.read().resolved_groups());
+    if *current_group.peek() != current {
+        current_group.set(current);
+    }
+}
+
+/// Keeps the recent outcomes and shows the latest ones for a moment
+fn use_outcome_listener(
+    mut history: Signal<OutcomeHistory>,
+    mut toasts: Signal<Vec<ActionReport>>,
+) -> UnboundedSender<ActionReport> {
+    let listener = use_coroutine(
+        move |mut receiver: UnboundedReceiver<ActionReport>| async move {
+            loop {
+                tokio::select! {
+                    report = receiver.next() => match report {
+                        Some(report) => history.write().push(report),
+                        None => break,
+                    },
+                    // wakes up to hide expired toasts
+                    _ = tokio::time::sleep(CURRENT_GROUP_POLL) => {}
+                }
+                let visible: Vec<ActionReport> = history
+                    .peek()
+                    .within(TOAST_DURATION)
+                    .take(TOAST_LIMIT)
+                    .cloned()
+                    .collect();
+                if *toasts.peek() != visible {
+                    toasts.set(visible);
+                }
+            }
+        },
+    );
+    listener.tx()
+}
+
 fn use_group_list_listener(config_service: Signal<ConfigService>, selected: Signal<HashSet<Uuid>>) {
     let handle_app_change = use_coroutine(
         move |mut receiver: UnboundedReceiver<ListOperation<Uuid>>| async move {
@@ -110,5 +309,11 @@ fn do_group_list_operation(
                 cs.remove_group(group_id)
             }
         }
+        ListOperation::Reparent(group_id, parent_id) => {
+            if !cs.set_parent(group_id, parent_id) {
+                warn!("A group can't be nested in itself");
+            }
+        }
+        ListOperation::InsertBefore(..) => {} // groups are nested, not reordered
     }
 }
@@ -0,0 +1,97 @@
+use std::path::PathBuf;
+
+use dioxus::prelude::*;
+use uuid::Uuid;
+
+use crate::models::{AppMatcher, GroupRule};
+use crate::services::ConfigService;
+
+const RULE_KINDS: [&str; 3] = ["Picked apps only", "Apps matching", "Apps installed in"];
+
+#[component]
+pub fn RuleConfig(config_service: Signal<ConfigService>, group_id: Uuid) -> Element {
+    let group = use_memo(move || config_service.read().group(group_id).unwrap().clone());
+    let mut kind = use_signal(|| match group().rule {
+        None => RULE_KINDS[0],
+        Some(GroupRule::Apps(_)) => RULE_KINDS[1],
+        Some(GroupRule::InstalledIn(_)) => RULE_KINDS[2],
+    });
+    let mut text = use_signal(|| match group().rule {
+        Some(GroupRule::Apps(matcher)) => matcher.to_string(),
+        Some(GroupRule::InstalledIn(folder)) => folder.to_string_lossy().into_owned(),
+        None => String::new(),
+    });
+    let rule = use_memo(move || match kind() {
+        k if k == RULE_KINDS[1] => Some(GroupRule::Apps(AppMatcher::glob(text()))),
+        k if k == RULE_KINDS[2] => Some(GroupRule::InstalledIn(PathBuf::from(text()))),
+        _ => None,
+    });
+    // input only commits on enter or blur, mounting the page leaves the rule as it is
+    use_effect(move || {
+        let rule = rule();
+        let unchanged = config_service
+            .peek()
+            .group(group_id)
+            .is_some_and(|g| g.rule == rule);
+        if !unchanged && rule.as_ref().is_none_or(|r| r.is_valid()) {
+            config_service.write().set_rule(group_id, rule);
+        }
+    });
+    let excluded_count = group().excluded().len();
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-2",
+            div {
+                class: "flex gap-2",
+                select {
+                    class: "select select-sm",
+                    onchange: move |evt| {
+                        if let Some(k) = RULE_KINDS.into_iter().find(|k| *k == evt.value()) {
+                            kind.set(k);
+                        }
+                    },
+                    for option_kind in RULE_KINDS {
+                        option {
+                            value: option_kind,
+                            selected: option_kind == kind(),
+                            "{option_kind}"
+                        }
+                    }
+                }
+                if rule().is_some() {
+                    input {
+                        class: "input input-sm",
+                        placeholder: match kind() {
+                            k if k == RULE_KINDS[1] => "com.jetbrains.*",
+                            _ => "~/Applications/Games",
+                        },
+                        value: "{text}",
+                        onchange: move |evt| text.set(evt.value()),
+                    }
+                    button {
+                        class: "btn btn-sm btn-ghost",
+                        onclick: move |_| {
+                            spawn(async move {
+                                let installed_apps = ConfigService::scan_installed_apps().await;
+                                config_service.write().set_installed_apps(installed_apps);
+                            });
+                        },
+                        "Refresh"
+                    }
+                }
+            }
+            if excluded_count > 0 {
+                div {
+                    class: "flex items-center gap-2 text-sm opacity-70",
+                    "{excluded_count} excluded"
+                    button {
+                        class: "btn btn-xs btn-ghost",
+                        onclick: move |_| config_service.write().clear_exclusions(group_id),
+                        "Include again"
+                    }
+                }
+            }
+        }
+    }
+}
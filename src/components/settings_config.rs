@@ -0,0 +1,52 @@
+use dioxus::prelude::*;
+
+use crate::components::util::{HotkeyPicker, HotkeyStatus};
+use crate::models::Action;
+use crate::services::ConfigService;
+
+#[component]
+pub fn SettingsConfig(config_service: Signal<ConfigService>) -> Element {
+    let settings = use_memo(move || config_service.read().settings().clone());
+    let picked_hotkey = use_signal(|| settings().previous_group_shortcut.hotkey);
+    use_effect(move || {
+        config_service
+            .write()
+            .set_hotkey(Action::PreviousGroup, picked_hotkey());
+    });
+    let pause_hotkey = use_signal(|| settings().pause_shortcut.hotkey);
+    use_effect(move || {
+        config_service
+            .write()
+            .set_hotkey(Action::ToggleHotkeysPaused, pause_hotkey());
+    });
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-2",
+            span { class: "text-sm font-bold", "Settings" }
+            label {
+                class: "flex items-center gap-2 text-sm",
+                "Previous group"
+                HotkeyPicker { picked_hotkey }
+            }
+            HotkeyStatus { config_service, action: Action::PreviousGroup }
+            label {
+                class: "flex items-center gap-2 text-sm",
+                "Pause hotkeys"
+                HotkeyPicker { picked_hotkey: pause_hotkey }
+            }
+            HotkeyStatus { config_service, action: Action::ToggleHotkeysPaused }
+            // also toggled by the pause hotkey, so it is read from the settings directly
+            label {
+                class: "flex items-center gap-2 text-sm",
+                input {
+                    class: "toggle toggle-sm",
+                    r#type: "checkbox",
+                    checked: settings().hotkeys_paused,
+                    onchange: move |evt| config_service.write().set_hotkeys_paused(evt.checked()),
+                }
+                "Hotkeys paused"
+            }
+        }
+    }
+}
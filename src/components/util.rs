@@ -1,5 +1,19 @@
+mod action_picker;
+mod chord_picker;
+mod context_picker;
 mod editable_text;
 mod hotkey_picker;
+mod hotkey_status;
+mod pick_app;
+mod trigger_picker;
+mod window_policy_picker;
 
+pub use action_picker::ActionPicker;
+pub use chord_picker::ChordPicker;
+pub use context_picker::ContextPicker;
 pub use editable_text::EditableText;
 pub use hotkey_picker::HotkeyPicker;
+pub use hotkey_status::HotkeyStatus;
+pub use pick_app::pick_app;
+pub use trigger_picker::TriggerPicker;
+pub use window_policy_picker::WindowPolicyPicker;
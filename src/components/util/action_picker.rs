@@ -0,0 +1,251 @@
+use std::fmt::{Display, Formatter};
+
+use dioxus::prelude::*;
+use uuid::Uuid;
+
+use crate::components::util::pick_app;
+use crate::models::{Action, Identifiable, Step};
+
+/// The general-purpose actions a binding or a step can run
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ActionKind {
+    OpenApp,
+    OpenUrl,
+    RunCommand,
+    OpenGroup,
+    PreviousGroup,
+    TogglePause,
+    Sequence,
+}
+
+impl ActionKind {
+    fn all() -> [Self; 7] {
+        [
+            ActionKind::OpenApp,
+            ActionKind::OpenUrl,
+            ActionKind::RunCommand,
+            ActionKind::OpenGroup,
+            ActionKind::PreviousGroup,
+            ActionKind::TogglePause,
+            ActionKind::Sequence,
+        ]
+    }
+
+    /// None for the actions which are bound to a group or resolved by the hotkeys instead
+    fn of(action: &Action) -> Option<Self> {
+        match action {
+            Action::OpenApp { .. } => Some(ActionKind::OpenApp),
+            Action::OpenUrl { .. } => Some(ActionKind::OpenUrl),
+            Action::RunCommand { .. } => Some(ActionKind::RunCommand),
+            Action::ActivateGroupByName { .. } => Some(ActionKind::OpenGroup),
+            Action::PreviousGroup => Some(ActionKind::PreviousGroup),
+            Action::ToggleHotkeysPaused => Some(ActionKind::TogglePause),
+            Action::Sequence { .. } => Some(ActionKind::Sequence),
+            Action::OpenGroup { .. }
+            | Action::LaunchGroup { .. }
+            | Action::RestoreHidden { .. }
+            | Action::Binding { .. }
+            | Action::ChordKey { .. }
+            | Action::TriggerKey { .. } => None,
+            #[cfg(test)]
+            Action::Mock(_) => None,
+        }
+    }
+
+    /// The action with the typed text, which kinds without a text ignore
+    fn with_text(self, text: String) -> Action {
+        match self {
+            ActionKind::OpenApp => Action::OpenApp { app_id: text },
+            ActionKind::OpenUrl => Action::OpenUrl { url: text },
+            ActionKind::RunCommand => Action::RunCommand { command: text },
+            ActionKind::OpenGroup => Action::ActivateGroupByName { name: text },
+            ActionKind::PreviousGroup => Action::PreviousGroup,
+            ActionKind::TogglePause => Action::ToggleHotkeysPaused,
+            ActionKind::Sequence => Action::Sequence { steps: Vec::new() },
+        }
+    }
+
+    fn placeholder(self) -> Option<&'static str> {
+        match self {
+            ActionKind::OpenApp => Some("App id"),
+            ActionKind::OpenUrl => Some("https://..."),
+            ActionKind::RunCommand => Some("Shell command"),
+            ActionKind::OpenGroup => Some("Group name"),
+            ActionKind::PreviousGroup | ActionKind::TogglePause | ActionKind::Sequence => None,
+        }
+    }
+}
+
+impl Display for ActionKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let msg = match self {
+            ActionKind::OpenApp => "Open app",
+            ActionKind::OpenUrl => "Open URL",
+            ActionKind::RunCommand => "Run command",
+            ActionKind::OpenGroup => "Open group",
+            ActionKind::PreviousGroup => "Previous group",
+            ActionKind::TogglePause => "Pause or resume hotkeys",
+            ActionKind::Sequence => "Sequence",
+        };
+        write!(f, "{msg}")
+    }
+}
+
+/// Edits the general-purpose actions, sequences can't be nested
+#[component]
+pub fn ActionPicker(
+    mut action: Signal<Action>,
+    #[props(default = true)] allow_sequence: bool,
+) -> Element {
+    let kinds = ActionKind::all()
+        .into_iter()
+        .filter(|k| allow_sequence || *k != ActionKind::Sequence);
+    let kind = ActionKind::of(&action());
+    let text = match action() {
+        Action::OpenApp { app_id } => app_id,
+        Action::OpenUrl { url } => url,
+        Action::RunCommand { command } => command,
+        Action::ActivateGroupByName { name } => name,
+        _ => String::new(),
+    };
+    let set_text = move |evt: Event<FormData>| {
+        if let Some(kind) = ActionKind::of(&action()) {
+            action.set(kind.with_text(evt.value()));
+        }
+    };
+    let pick_app = move |_| async move {
+        if let Some(app) = pick_app().await {
+            action.set(Action::OpenApp { app_id: app.id() });
+        }
+    };
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-2",
+            div {
+                class: "flex gap-2",
+                select {
+                    class: "select select-sm",
+                    onchange: move |evt| {
+                        let picked = evt.value();
+                        let all = ActionKind::all();
+                        if let Some(k) = all.into_iter().find(|k| k.to_string() == picked) {
+                            action.set(k.with_text(String::new()));
+                        }
+                    },
+                    for option_kind in kinds {
+                        option {
+                            value: "{option_kind}",
+                            selected: Some(option_kind) == kind,
+                            "{option_kind}"
+                        }
+                    }
+                }
+                if let Some(placeholder) = kind.and_then(ActionKind::placeholder) {
+                    input {
+                        class: "input input-sm grow",
+                        placeholder,
+                        value: "{text}",
+                        onchange: set_text,
+                    }
+                }
+                if let Action::OpenApp { .. } = action() {
+                    button { class: "btn btn-sm", onclick: pick_app, "Pick..." }
+                }
+            }
+            if let Action::Sequence { steps } = action() {
+                SequenceEditor { action, steps }
+            }
+        }
+    }
+}
+
+#[component]
+fn SequenceEditor(mut action: Signal<Action>, steps: Vec<Step>) -> Element {
+    // keys the step editors, kept in line with the steps as they are added and removed
+    let mut step_ids = use_signal(|| steps.iter().map(|_| Uuid::new_v4()).collect::<Vec<_>>());
+    let add_step = move |_| {
+        if let Action::Sequence { steps } = &mut *action.write() {
+            steps.push(Step {
+                action: Action::PreviousGroup,
+                delay_ms: 0,
+            });
+            step_ids.write().push(Uuid::new_v4());
+        }
+    };
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-2 pl-4 border-l",
+            for (step_id, step) in step_ids().into_iter().zip(steps) {
+                StepEditor { key: "{step_id}", sequence: action, step_ids, step_id, step }
+            }
+            button { class: "btn btn-xs w-fit", onclick: add_step, "Add step" }
+        }
+    }
+}
+
+#[component]
+fn StepEditor(
+    mut sequence: Signal<Action>,
+    mut step_ids: Signal<Vec<Uuid>>,
+    step_id: Uuid,
+    step: Step,
+) -> Element {
+    // looked up when needed, the steps before it may have been removed meanwhile
+    let index = move || step_ids.peek().iter().position(|id| *id == step_id);
+    let action = use_signal(|| step.action.clone());
+    let mut delay_ms = use_signal(|| step.delay_ms);
+    use_effect(move || {
+        let step = Step {
+            action: action(),
+            delay_ms: delay_ms(),
+        };
+        let Some(index) = index() else {
+            return;
+        };
+        // peeked, so the sequence doesn't subscribe to itself, and only written on changes
+        let unchanged = match &*sequence.peek() {
+            Action::Sequence { steps } => steps.get(index).is_none_or(|s| *s == step),
+            _ => true,
+        };
+        if unchanged {
+            return;
+        }
+        if let Action::Sequence { steps } = &mut *sequence.write() {
+            steps[index] = step;
+        }
+    });
+    let remove = move |_| {
+        let Some(index) = index() else {
+            return;
+        };
+        if let Action::Sequence { steps } = &mut *sequence.write() {
+            steps.remove(index);
+            step_ids.write().remove(index);
+        }
+    };
+
+    rsx! {
+        div {
+            class: "flex items-start gap-2",
+            label {
+                class: "flex items-center gap-1 text-sm",
+                "Wait (ms)"
+                input {
+                    class: "input input-sm w-20",
+                    r#type: "number",
+                    min: "0",
+                    value: "{delay_ms}",
+                    oninput: move |evt| {
+                        if let Ok(ms) = evt.value().parse() {
+                            delay_ms.set(ms);
+                        }
+                    },
+                }
+            }
+            ActionPicker { action, allow_sequence: false }
+            button { class: "btn btn-xs btn-ghost", onclick: remove, "Remove" }
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use dioxus::prelude::*;
+use futures_util::StreamExt;
+
+use crate::models::{Chord, Hotkey};
+use crate::services::SharedSender;
+use crate::util::is_modifier;
+
+/// Records a leader and the keys after it. Every key is part of the chord, even Enter and
+/// Escape, so recording is finished with the buttons.
+#[component]
+pub fn ChordPicker(mut picked_chord: Signal<Option<Chord>>) -> Element {
+    let mut recording = use_signal(|| false);
+    let mut keys = use_signal(Vec::<Hotkey>::new);
+    let record_unregistered = move |evt: KeyboardEvent| {
+        let code = evt.code();
+        if !recording() || is_modifier(&code) {
+            return;
+        }
+        evt.prevent_default(); // Enter or Space would click the focused button otherwise
+        keys.write().push(Hotkey::new(evt.modifiers(), code));
+    };
+    let confirm = move |_: MouseEvent| {
+        recording.set(false);
+        if let Some(chord) = Chord::new(keys.take()) {
+            picked_chord.set(Some(chord));
+        }
+    };
+    use_record_registered(recording, keys);
+
+    let label = if recording() {
+        let typed: Vec<String> = keys().iter().map(|k| k.to_string()).collect();
+        let typed = typed.join(" then ");
+        rsx! {
+            span { class: "text-base-content", "Recording... {typed}" }
+        }
+    } else {
+        match picked_chord() {
+            None => rsx! {
+                span { class: "opacity-50", "No chord" }
+            },
+            Some(chord) => rsx! {
+                span { class: "text-base-content", "{chord}" }
+            },
+        }
+    };
+    rsx! {
+        div {
+            onkeydown: record_unregistered, // globally registered keys never make it here
+            tabindex: 0,
+            class: "flex gap-2",
+            button {
+                class: "btn btn-sm btn-outline",
+                onclick: move |_| {
+                    keys.write().clear();
+                    recording.set(true);
+                },
+                { label }
+            }
+            if recording() {
+                button {
+                    class: "btn btn-sm btn-primary",
+                    disabled: keys().len() < 2,
+                    onclick: confirm,
+                    "Done"
+                }
+                button {
+                    class: "btn btn-sm btn-ghost",
+                    onclick: move |_| {
+                        recording.set(false);
+                        keys.write().clear();
+                    },
+                    "Cancel"
+                }
+            } else if picked_chord().is_some() {
+                button {
+                    class: "btn btn-sm btn-ghost",
+                    onclick: move |_| picked_chord.set(None),
+                    "Clear"
+                }
+            }
+        }
+    }
+}
+
+// the leader may already be registered for another chord
+fn use_record_registered(recording: Signal<bool>, mut keys: Signal<Vec<Hotkey>>) {
+    let listener = use_coroutine(move |mut receiver: UnboundedReceiver<Hotkey>| async move {
+        while let Some(hotkey) = receiver.next().await {
+            keys.write().push(hotkey);
+        }
+    });
+    let record_registered_sender = use_context::<SharedSender<Hotkey>>();
+    use_effect(move || {
+        record_registered_sender.set(if !recording() {
+            None
+        } else {
+            Some(listener.tx())
+        });
+    });
+}
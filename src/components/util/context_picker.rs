@@ -0,0 +1,42 @@
+use dioxus::prelude::*;
+
+use crate::models::HotkeyContext;
+
+#[component]
+pub fn ContextPicker(mut context: Signal<HotkeyContext>) -> Element {
+    // kept as typed, so separators don't vanish while typing
+    let mut only_in = use_signal(|| context.peek().only_in.join(", "));
+    let mut excluded = use_signal(|| context.peek().excluded.join(", "));
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-2",
+            input {
+                class: "input input-sm",
+                placeholder: "Only in apps, e.g. com.apple.Safari",
+                value: "{only_in}",
+                oninput: move |evt| {
+                    only_in.set(evt.value());
+                    context.write().only_in = parse_apps(&evt.value());
+                },
+            }
+            input {
+                class: "input input-sm",
+                placeholder: "Not in apps",
+                value: "{excluded}",
+                oninput: move |evt| {
+                    excluded.set(evt.value());
+                    context.write().excluded = parse_apps(&evt.value());
+                },
+            }
+        }
+    }
+}
+
+fn parse_apps(text: &str) -> Vec<String> {
+    text.split(',')
+        .map(str::trim)
+        .filter(|app| !app.is_empty())
+        .map(str::to_string)
+        .collect()
+}
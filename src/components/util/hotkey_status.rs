@@ -0,0 +1,19 @@
+use dioxus::prelude::*;
+
+use crate::models::Action;
+use crate::services::ConfigService;
+
+/// Marks a hotkey the OS refused, e.g. because another app registered it first
+#[component]
+pub fn HotkeyStatus(config_service: Signal<ConfigService>, action: Action) -> Element {
+    let error = config_service
+        .read()
+        .hotkey_error(&action)
+        .map(|e| e.to_string());
+
+    rsx! {
+        if let Some(error) = error {
+            span { class: "text-sm text-error", "Could not register: {error}" }
+        }
+    }
+}
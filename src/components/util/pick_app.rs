@@ -0,0 +1,19 @@
+use rfd::{AsyncMessageDialog, MessageLevel};
+
+use crate::os::{App, AppDialog, AppSelection};
+
+/// Asks for an app, telling the user why if the picked file can't be used
+pub async fn pick_app() -> Option<App> {
+    match AppDialog::select_app().await {
+        Ok(app) => app,
+        Err(e) => {
+            AsyncMessageDialog::new()
+                .set_level(MessageLevel::Warning)
+                .set_title("Could not add the app")
+                .set_description(e.to_string())
+                .show()
+                .await;
+            None
+        }
+    }
+}
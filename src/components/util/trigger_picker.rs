@@ -0,0 +1,25 @@
+use dioxus::prelude::*;
+
+use crate::models::Trigger;
+
+#[component]
+pub fn TriggerPicker(mut trigger: Signal<Trigger>) -> Element {
+    rsx! {
+        select {
+            class: "select select-sm w-32",
+            onchange: move |evt| {
+                let picked = evt.value();
+                if let Some(t) = Trigger::all().into_iter().find(|t| t.to_string() == picked) {
+                    trigger.set(t);
+                }
+            },
+            for option_trigger in Trigger::all() {
+                option {
+                    value: "{option_trigger}",
+                    selected: option_trigger == trigger(),
+                    "{option_trigger}"
+                }
+            }
+        }
+    }
+}
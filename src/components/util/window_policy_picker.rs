@@ -0,0 +1,40 @@
+use dioxus::prelude::*;
+
+use crate::models::WindowPolicy;
+
+#[component]
+pub fn WindowPolicyPicker(mut window_policy: Signal<WindowPolicy>) -> Element {
+    let on_kind_change = move |evt: Event<FormData>| {
+        if let Some(policy) = WindowPolicy::from_kind(&evt.value()) {
+            window_policy.set(policy);
+        }
+    };
+    let on_pattern_change = move |evt: Event<FormData>| {
+        window_policy.set(WindowPolicy::TitleMatch(evt.value()));
+    };
+
+    rsx! {
+        div {
+            class: "flex gap-2",
+            select {
+                class: "select select-sm",
+                onchange: on_kind_change,
+                for policy in WindowPolicy::all() {
+                    option {
+                        value: policy.kind(),
+                        selected: policy.kind() == window_policy().kind(),
+                        "{policy}"
+                    }
+                }
+            }
+            if let WindowPolicy::TitleMatch(pattern) = window_policy() {
+                input {
+                    class: "input input-sm",
+                    placeholder: "Title contains...",
+                    value: "{pattern}",
+                    oninput: on_pattern_change,
+                }
+            }
+        }
+    }
+}
@@ -1,11 +1,33 @@
 mod action;
+mod app_entry;
+mod binding;
+mod chord;
 mod config;
+mod context;
 mod group;
 mod hotkey;
+mod matcher;
+mod member;
+mod rule;
+mod settings;
+mod shortcut;
 mod traits;
+mod trigger;
+mod window;
 
-pub use action::{Action, Bindable};
+pub use action::{Action, Bindable, Step};
+pub use app_entry::{AppEntry, LaunchOptions};
+pub use binding::Binding;
+pub use chord::Chord;
 pub use config::Config;
-pub use group::Group;
+pub use context::HotkeyContext;
+pub use group::{Group, GroupMode, MemberFlags};
 pub use hotkey::Hotkey;
+pub use matcher::AppMatcher;
+pub use member::{Member, MemberKind};
+pub use rule::GroupRule;
+pub use settings::Settings;
+pub use shortcut::Shortcut;
 pub use traits::Identifiable;
+pub use trigger::Trigger;
+pub use window::{TitlePattern, WindowInfo, WindowPolicy};
@@ -1,23 +1,98 @@
 use std::fmt::{Display, Formatter};
+use std::time::Duration;
 
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::models::group::Group;
-use crate::models::{Hotkey, Identifiable};
+use crate::models::{Hotkey, Identifiable, Shortcut};
 
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
 pub enum Action {
     OpenGroup {
         group_id: Uuid,
     },
+    LaunchGroup {
+        group_id: Uuid,
+    },
+    RestoreHidden {
+        group_id: Uuid,
+    },
+    PreviousGroup,
+    /// Unbinds every other hotkey, or binds them again
+    ToggleHotkeysPaused,
+    OpenApp {
+        app_id: String,
+    },
+    OpenUrl {
+        url: String,
+    },
+    RunCommand {
+        command: String,
+    },
+    /// Looked up when executed, so it keeps working if the group is recreated
+    ActivateGroupByName {
+        name: String,
+    },
+    /// Runs the steps in order, a macro
+    Sequence {
+        steps: Vec<Step>,
+    },
+    /// Runs the action of a standalone binding
+    Binding {
+        binding_id: Uuid,
+    },
+    /// A key of a chord was pressed, only sent by the hotkeys themselves
+    #[serde(skip)]
+    ChordKey {
+        hotkey: Hotkey,
+    },
+    /// A hotkey with several triggers was pressed or released, only sent by the hotkeys themselves
+    #[serde(skip)]
+    TriggerKey {
+        hotkey: Hotkey,
+        pressed: bool,
+    },
     #[cfg(test)]
+    #[serde(skip)]
     Mock(&'static str),
 }
 
+/// An action in a sequence, optionally waiting before it
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct Step {
+    pub action: Action,
+    #[serde(default)]
+    pub delay_ms: u64,
+}
+
+impl Step {
+    pub fn delay(&self) -> Duration {
+        Duration::from_millis(self.delay_ms)
+    }
+}
+
 impl Display for Action {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let msg = match self {
             Action::OpenGroup { group_id } => format!("Open group {group_id}"),
+            Action::LaunchGroup { group_id } => format!("Launch group {group_id}"),
+            Action::RestoreHidden { group_id } => {
+                format!("Restore apps hidden by group {group_id}")
+            }
+            Action::PreviousGroup => "Previous group".to_string(),
+            Action::ToggleHotkeysPaused => "Pause or resume hotkeys".to_string(),
+            Action::OpenApp { app_id } => format!("Open app {app_id}"),
+            Action::OpenUrl { url } => format!("Open {url}"),
+            Action::RunCommand { command } => format!("Run '{command}'"),
+            Action::ActivateGroupByName { name } => format!("Open group '{name}'"),
+            Action::Sequence { steps } => format!("Sequence of {} actions", steps.len()),
+            Action::Binding { binding_id } => format!("Binding {binding_id}"),
+            Action::ChordKey { hotkey } => format!("Chord key {hotkey}"),
+            Action::TriggerKey { hotkey, pressed } => match pressed {
+                true => format!("Pressed {hotkey}"),
+                false => format!("Released {hotkey}"),
+            },
             #[cfg(test)]
             Action::Mock(str) => format!("Mock {str}"),
         };
@@ -26,14 +101,58 @@ impl Display for Action {
 }
 
 pub trait Bindable {
-    fn binding(&self) -> (Option<Hotkey>, Action);
+    fn bindings(&self) -> Vec<(&Shortcut, Action)>;
 }
 
 impl Bindable for Group {
-    fn binding(&self) -> (Option<Hotkey>, Action) {
-        let action = Action::OpenGroup {
-            group_id: self.id(),
+    fn bindings(&self) -> Vec<(&Shortcut, Action)> {
+        let group_id = self.id();
+        vec![
+            (&self.shortcut, Action::OpenGroup { group_id }),
+            (&self.launch_shortcut, Action::LaunchGroup { group_id }),
+            (&self.restore_shortcut, Action::RestoreHidden { group_id }),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sequence_roundtrip() {
+        // Arrange
+        let action = Action::Sequence {
+            steps: vec![
+                Step {
+                    action: Action::OpenUrl {
+                        url: "https://example.com".to_string(),
+                    },
+                    delay_ms: 0,
+                },
+                Step {
+                    action: Action::ActivateGroupByName {
+                        name: "Chat".to_string(),
+                    },
+                    delay_ms: 500,
+                },
+            ],
         };
-        (self.hotkey, action)
+
+        // Act
+        let json = serde_json::to_string(&action).unwrap();
+        let parsed: Action = serde_json::from_str(&json).unwrap();
+
+        // Assert
+        assert_eq!(parsed, action);
+    }
+
+    #[test]
+    fn step_delay_defaults_to_zero() {
+        // Act
+        let step: Step = serde_json::from_str(r#"{"action":"PreviousGroup"}"#).unwrap();
+
+        // Assert
+        assert_eq!(step.delay(), Duration::ZERO);
     }
 }
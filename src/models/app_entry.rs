@@ -0,0 +1,113 @@
+use std::fmt::{Display, Formatter};
+use std::path::PathBuf;
+
+use crate::models::{Identifiable, WindowPolicy};
+use crate::os::{App, OpenError, Openable};
+
+/// How to start an app when it is not running yet
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LaunchOptions {
+    pub args: Vec<String>,
+    pub working_dir: Option<PathBuf>,
+    pub env: Vec<(String, String)>,
+}
+
+impl LaunchOptions {
+    pub fn is_empty(&self) -> bool {
+        self.args.is_empty() && self.working_dir.is_none() && self.env.is_empty()
+    }
+
+    /// One argument per line
+    pub fn parse_args(text: &str) -> Vec<String> {
+        text.lines()
+            .map(str::trim)
+            .filter(|l| !l.is_empty())
+            .map(String::from)
+            .collect()
+    }
+
+    /// One `KEY=VALUE` pair per line, lines without `=` are skipped
+    pub fn parse_env(text: &str) -> Vec<(String, String)> {
+        text.lines()
+            .filter_map(|l| l.split_once('='))
+            .map(|(k, v)| (k.trim().to_string(), v.trim().to_string()))
+            .filter(|(k, _)| !k.is_empty())
+            .collect()
+    }
+
+    pub fn format_env(&self) -> String {
+        self.env
+            .iter()
+            .map(|(k, v)| format!("{k}={v}"))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// An app as a member of a group
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AppEntry {
+    pub app: App,
+    pub launch_options: LaunchOptions,
+}
+
+impl AppEntry {
+    pub fn new(app: App) -> Self {
+        Self {
+            app,
+            launch_options: LaunchOptions::default(),
+        }
+    }
+
+    pub fn open(&self, window_policy: &WindowPolicy) -> Result<(), OpenError> {
+        self.app.open(window_policy, &self.launch_options)
+    }
+}
+
+impl Identifiable<String> for AppEntry {
+    fn id(&self) -> String {
+        self.app.id()
+    }
+}
+
+impl Display for AppEntry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.app)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_args_skips_blank_lines() {
+        // Act
+        let args = LaunchOptions::parse_args("--profile\n\n  work  \n");
+
+        // Assert
+        assert_eq!(args, vec!["--profile", "work"]);
+    }
+
+    #[test]
+    fn parse_env_roundtrip() {
+        // Arrange
+        let text = "RUST_LOG=debug\ninvalid\nURL=http://x?a=b";
+
+        // Act
+        let options = LaunchOptions {
+            env: LaunchOptions::parse_env(text),
+            ..Default::default()
+        };
+
+        // Assert
+        assert_eq!(
+            options.env,
+            vec![
+                ("RUST_LOG".to_string(), "debug".to_string()),
+                ("URL".to_string(), "http://x?a=b".to_string())
+            ]
+        );
+        assert_eq!(options.format_env(), "RUST_LOG=debug\nURL=http://x?a=b");
+    }
+}
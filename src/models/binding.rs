@@ -0,0 +1,48 @@
+use std::fmt::{Display, Formatter};
+
+use uuid::Uuid;
+
+use crate::models::{Action, Bindable, Identifiable, Shortcut};
+
+/// A hotkey for any action, not attached to a group
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Binding {
+    id: Uuid,
+    pub name: String,
+    pub shortcut: Shortcut,
+    pub action: Action,
+    /// Disabled bindings keep their hotkey and chord reserved, but they aren't registered
+    pub enabled: bool,
+}
+
+impl Identifiable<Uuid> for Binding {
+    fn id(&self) -> Uuid {
+        self.id
+    }
+}
+
+impl Binding {
+    pub fn new(name: String) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            name,
+            shortcut: Shortcut::default(),
+            action: Action::PreviousGroup,
+            enabled: true,
+        }
+    }
+}
+
+impl Bindable for Binding {
+    // the hotkey refers to the binding, so editing its action doesn't need a rebind
+    fn bindings(&self) -> Vec<(&Shortcut, Action)> {
+        let binding_id = self.id;
+        vec![(&self.shortcut, Action::Binding { binding_id })]
+    }
+}
+
+impl Display for Binding {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
@@ -0,0 +1,54 @@
+use std::fmt::{Display, Formatter};
+
+use crate::models::Hotkey;
+
+/// A leader hotkey followed by more keys, e.g. Ctrl+Space then B.
+/// Only the leader is registered globally, the other keys while the chord is pending.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct Chord {
+    keys: Vec<Hotkey>,
+}
+
+impl Chord {
+    /// A chord needs at least a leader and one more key
+    pub fn new(keys: Vec<Hotkey>) -> Option<Self> {
+        (keys.len() >= 2).then_some(Self { keys })
+    }
+
+    pub fn leader(&self) -> Hotkey {
+        self.keys[0]
+    }
+
+    pub fn keys(&self) -> &[Hotkey] {
+        &self.keys
+    }
+}
+
+impl Display for Chord {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let keys: Vec<String> = self.keys.iter().map(|k| k.to_string()).collect();
+        write!(f, "{}", keys.join(" then "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use global_hotkey::hotkey::{Code, Modifiers};
+
+    use super::*;
+
+    #[test]
+    fn chord_needs_two_keys() {
+        // Arrange
+        let leader = Hotkey::new(Modifiers::CONTROL, Code::Space);
+        let key = Hotkey::new(Modifiers::empty(), Code::KeyB);
+
+        // Act
+        let single = Chord::new(vec![leader]);
+        let chord = Chord::new(vec![leader, key]);
+
+        // Assert
+        assert_eq!(single, None);
+        assert_eq!(chord.unwrap().leader(), leader);
+    }
+}
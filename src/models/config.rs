@@ -1,25 +1,124 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+use anyhow::bail;
+use log::warn;
 use uuid::Uuid;
 
 use crate::models::group::Group;
 use crate::models::hotkey::Hotkey;
-use crate::models::{Action, Bindable, Identifiable};
+use crate::models::{
+    Action, Bindable, Binding, Chord, GroupMode, GroupRule, HotkeyContext, Identifiable,
+    LaunchOptions, Member, MemberFlags, Settings, Shortcut, Trigger, WindowPolicy,
+};
 use crate::os::App;
 
-#[derive(Default)]
+#[derive(Default, Clone)]
 pub struct Config {
     groups: Vec<Group>,
-    // settings: Settings as enum (can implement Actionable)
+    settings: Settings,
+    bindings: Vec<Binding>,
+    installed_apps: Vec<(App, PathBuf)>, // the last scan, for rules and matchers
 }
 
 impl Config {
-    pub fn bindings(&self) -> Vec<(Option<Hotkey>, Action)> {
-        self.groups.iter().map(|g| g.binding()).collect()
+    pub fn bindings(&self) -> Vec<(&Shortcut, Action)> {
+        self.groups
+            .iter()
+            .flat_map(|g| g.bindings())
+            .chain(self.settings.bindings())
+            .chain(self.bindings.iter().flat_map(|b| b.bindings()))
+            .collect()
+    }
+
+    pub fn trigger(&self, action: &Action) -> Trigger {
+        self.shortcut(action).map(|s| s.trigger).unwrap_or_default()
+    }
+
+    pub fn set_trigger(&mut self, action: &Action, trigger: Trigger) -> anyhow::Result<()> {
+        self.shortcut_mut(action)?.trigger = trigger;
+        Ok(())
+    }
+
+    pub fn context(&self, action: &Action) -> HotkeyContext {
+        self.shortcut(action)
+            .map(|s| s.context.clone())
+            .unwrap_or_default()
+    }
+
+    pub fn set_context(&mut self, action: &Action, context: HotkeyContext) -> anyhow::Result<()> {
+        self.shortcut_mut(action)?.context = context;
+        Ok(())
+    }
+
+    /// Whether any hotkey is limited to some apps
+    pub fn has_contexts(&self) -> bool {
+        self.bindings().iter().any(|(s, _)| !s.context.is_empty())
+    }
+
+    pub fn chords(&self) -> Vec<(Chord, Action)> {
+        self.bindings()
+            .into_iter()
+            .filter_map(|(s, a)| Some((s.chord.clone()?, a)))
+            .collect()
+    }
+
+    pub fn settings(&self) -> &Settings {
+        &self.settings
     }
 
     pub fn groups(&self) -> &Vec<Group> {
         &self.groups
     }
 
+    /// Standalone bindings, as opposed to the ones derived from groups and settings
+    pub fn standalone_bindings(&self) -> &Vec<Binding> {
+        &self.bindings
+    }
+
+    pub fn binding(&self, binding_id: Uuid) -> Option<&Binding> {
+        self.bindings.iter().find(|b| b.id() == binding_id)
+    }
+
+    fn binding_mut(&mut self, binding_id: Uuid) -> &mut Binding {
+        self.bindings
+            .iter_mut()
+            .find(|b| b.id() == binding_id)
+            .unwrap()
+    }
+
+    pub fn add_binding(&mut self, name: String) -> Uuid {
+        let binding = Binding::new(name);
+        let binding_id = binding.id();
+        self.bindings.push(binding);
+        binding_id
+    }
+
+    pub fn remove_binding(&mut self, binding_id: Uuid) {
+        self.bindings.retain(|b| b.id() != binding_id)
+    }
+
+    pub fn set_binding_name(&mut self, binding_id: Uuid, name: String) {
+        self.binding_mut(binding_id).name = name;
+    }
+
+    pub fn set_binding_enabled(&mut self, binding_id: Uuid, enabled: bool) {
+        self.binding_mut(binding_id).enabled = enabled;
+    }
+
+    pub fn set_binding_action(&mut self, binding_id: Uuid, action: Action) {
+        self.binding_mut(binding_id).action = action;
+    }
+
+    pub fn get_chord(&self, action: &Action) -> Option<Chord> {
+        self.shortcut(action)?.chord.clone()
+    }
+
+    pub fn set_chord(&mut self, action: &Action, chord: Option<Chord>) -> anyhow::Result<()> {
+        self.shortcut_mut(action)?.chord = chord;
+        Ok(())
+    }
+
     pub fn add_group(&mut self, name: String) -> Uuid {
         let group = Group::new(name);
         let group_id = group.id();
@@ -27,7 +126,20 @@ impl Config {
         group_id
     }
 
+    /// Nested groups move up to the removed group's parent
     pub fn remove_group(&mut self, group_id: Uuid) {
+        let Some(group) = self.group(group_id) else {
+            return;
+        };
+        let parent_id = group.parent_id;
+        for group in &mut self.groups {
+            if group.parent_id == Some(group_id) {
+                group.parent_id = parent_id;
+            }
+        }
+        for group in &mut self.groups {
+            group.remove_member(group_id.to_string());
+        }
         self.groups.retain(|g| g.id() != group_id)
     }
 
@@ -35,6 +147,149 @@ impl Config {
         self.groups.iter().find(|g| g.id() == group_id)
     }
 
+    /// Describes the action with the names of the groups and bindings it refers to
+    pub fn describe(&self, action: &Action) -> String {
+        let group_name = |group_id: &Uuid| self.group_name(*group_id);
+        match action {
+            Action::OpenGroup { group_id } => format!("Open group '{}'", group_name(group_id)),
+            Action::LaunchGroup { group_id } => format!("Launch group '{}'", group_name(group_id)),
+            Action::RestoreHidden { group_id } => {
+                format!("Restore apps hidden by '{}'", group_name(group_id))
+            }
+            Action::Binding { binding_id } => match self.binding(*binding_id) {
+                Some(binding) => binding.name.clone(),
+                None => action.to_string(),
+            },
+            _ => action.to_string(),
+        }
+    }
+
+    /// Included groups are shown by their current name
+    pub fn describe_member(&self, member: &Member) -> String {
+        match member {
+            Member::Group { group_id } => self.group_name(*group_id),
+            _ => member.to_string(),
+        }
+    }
+
+    fn group_name(&self, group_id: Uuid) -> String {
+        match self.group(group_id) {
+            Some(group) => group.name.clone(),
+            None => group_id.to_string(),
+        }
+    }
+
+    /// The group as it is activated, with the members of nested groups if it includes them
+    /// Included groups are expanded recursively, so changes to them apply right away.
+    pub fn resolved_group(&self, group_id: Uuid) -> Option<Group> {
+        let group = self.group(group_id)?;
+        let mut resolved = group.clone();
+        resolved.clear_members();
+        self.expand_into(group, &mut vec![], &mut resolved);
+        Some(resolved)
+    }
+
+    /// Every group as it is activated, see `resolved_group`
+    pub fn resolved_groups(&self) -> Vec<Group> {
+        self.groups
+            .iter()
+            .filter_map(|g| self.resolved_group(g.id()))
+            .collect()
+    }
+
+    fn expand_into(&self, group: &Group, path: &mut Vec<Uuid>, resolved: &mut Group) {
+        path.push(group.id());
+        let nested = match group.include_children {
+            true => self.descendants(group.id()),
+            false => Vec::new(),
+        };
+        // each member keeps the flags of the group it was added to
+        let members = std::iter::once(group)
+            .chain(nested.iter().copied())
+            .flat_map(|owner| owner.all_members().map(move |member| (owner, member)));
+        for (owner, member) in members {
+            match member {
+                Member::Group { group_id } => match self.group(*group_id) {
+                    Some(included) if path.contains(group_id) => {
+                        warn!("Group '{}' includes itself", included.name)
+                    }
+                    Some(included) => self.expand_into(included, path, resolved),
+                    None => warn!("Included group '{group_id}' no longer exists"),
+                },
+                member => {
+                    resolved.add_member(member.clone());
+                    // members without flags of their own leave them to later groups
+                    if let Some(flags) = owner.explicit_flags(&member.id()) {
+                        resolved.inherit_flags(&member.id(), flags);
+                    }
+                }
+            }
+        }
+        path.pop();
+    }
+
+    /// Whether `group_id` could include `other_id` without creating a cycle
+    pub fn can_include(&self, group_id: Uuid, other_id: Uuid) -> bool {
+        group_id != other_id && !self.includes(other_id, group_id)
+    }
+
+    /// Whether `group_id` includes `other_id`, directly or through other groups
+    fn includes(&self, group_id: Uuid, other_id: Uuid) -> bool {
+        let mut pending = vec![group_id];
+        let mut seen = Vec::new();
+        while let Some(id) = pending.pop() {
+            if id == other_id {
+                return true;
+            }
+            if seen.contains(&id) {
+                continue;
+            }
+            seen.push(id);
+            if let Some(group) = self.group(id) {
+                pending.extend(group.referenced_groups());
+            }
+        }
+        false
+    }
+
+    pub fn children(&self, group_id: Uuid) -> impl Iterator<Item = &Group> {
+        self.groups
+            .iter()
+            .filter(move |g| g.parent_id == Some(group_id))
+    }
+
+    /// All nested groups, depth first
+    pub fn descendants(&self, group_id: Uuid) -> Vec<&Group> {
+        let mut descendants = Vec::new();
+        for child in self.children(group_id) {
+            descendants.push(child);
+            descendants.extend(self.descendants(child.id()));
+        }
+        descendants
+    }
+
+    /// Moves a group into another one, or to the top level. A group can't be moved into itself
+    /// or one of its descendants, so the tree never has cycles.
+    pub fn set_parent(&mut self, group_id: Uuid, parent_id: Option<Uuid>) -> bool {
+        if let Some(parent_id) = parent_id {
+            let is_descendant = self
+                .descendants(group_id)
+                .iter()
+                .any(|g| g.id() == parent_id);
+            if parent_id == group_id || is_descendant || self.group(parent_id).is_none() {
+                return false;
+            }
+        }
+        let group = self.group_mut(group_id);
+        group.parent_id = parent_id;
+        true
+    }
+
+    pub fn set_include_children(&mut self, group_id: Uuid, include_children: bool) {
+        let group = self.group_mut(group_id);
+        group.include_children = include_children;
+    }
+
     fn group_mut(&mut self, group_id: Uuid) -> &mut Group {
         // TODO add better error handling
         self.groups.iter_mut().find(|g| g.id() == group_id).unwrap()
@@ -45,23 +300,415 @@ impl Config {
         group.name = name;
     }
 
-    pub fn add_app(&mut self, group_id: Uuid, app: App) {
+    pub fn set_mode(&mut self, group_id: Uuid, mode: GroupMode) {
         let group = self.group_mut(group_id);
-        group.add_app(app)
+        group.mode = mode;
     }
 
-    pub fn remove_app(&mut self, group_id: Uuid, app_id: String) {
+    pub fn set_window_policy(&mut self, group_id: Uuid, window_policy: WindowPolicy) {
         let group = self.group_mut(group_id);
-        group.remove_app(app_id)
+        group.window_policy = window_policy;
     }
 
-    pub fn get_binding(&self, group_id: Uuid) -> Option<(Option<Hotkey>, Action)> {
-        let group = self.group(group_id)?;
-        Some(group.binding())
+    pub fn add_member(&mut self, group_id: Uuid, member: Member) {
+        if let Member::Group { group_id: other_id } = &member
+            && !self.can_include(group_id, *other_id)
+        {
+            let name = self.group_name(*other_id);
+            warn!("Group '{name}' can't be included, it would include itself");
+            return;
+        }
+        let group = self.group_mut(group_id);
+        group.add_member(member)
+    }
+
+    pub fn remove_member(&mut self, group_id: Uuid, member_id: String) {
+        let group = self.group_mut(group_id);
+        group.remove_member(member_id)
+    }
+
+    pub fn move_member(&mut self, group_id: Uuid, member_id: &str, before_id: Option<&str>) {
+        let group = self.group_mut(group_id);
+        group.move_member(member_id, before_id)
     }
 
-    pub fn set_hotkey(&mut self, group_id: Uuid, hotkey: Option<Hotkey>) {
+    pub fn set_launch_options(
+        &mut self,
+        group_id: Uuid,
+        app_id: &str,
+        launch_options: LaunchOptions,
+    ) {
         let group = self.group_mut(group_id);
-        group.hotkey = hotkey;
+        group.set_launch_options(app_id, launch_options)
+    }
+
+    pub fn set_hotkeys_paused(&mut self, paused: bool) {
+        self.settings.hotkeys_paused = paused;
+    }
+
+    /// None for the actions which only get a shortcut through a binding
+    pub fn shortcut(&self, action: &Action) -> Option<&Shortcut> {
+        self.bindings()
+            .into_iter()
+            .find_map(|(s, a)| (a == *action).then_some(s))
+    }
+
+    fn shortcut_mut(&mut self, action: &Action) -> anyhow::Result<&mut Shortcut> {
+        match action {
+            Action::OpenGroup { group_id } => Ok(&mut self.group_mut(*group_id).shortcut),
+            Action::LaunchGroup { group_id } => Ok(&mut self.group_mut(*group_id).launch_shortcut),
+            Action::RestoreHidden { group_id } => {
+                Ok(&mut self.group_mut(*group_id).restore_shortcut)
+            }
+            Action::PreviousGroup => Ok(&mut self.settings.previous_group_shortcut),
+            Action::ToggleHotkeysPaused => Ok(&mut self.settings.pause_shortcut),
+            Action::Binding { binding_id } => Ok(&mut self.binding_mut(*binding_id).shortcut),
+            #[cfg(test)]
+            Action::Mock(_) => bail!("{action} has no shortcut"),
+            // general-purpose actions are only bound through a binding, key events never
+            Action::ChordKey { .. }
+            | Action::TriggerKey { .. }
+            | Action::OpenApp { .. }
+            | Action::OpenUrl { .. }
+            | Action::RunCommand { .. }
+            | Action::ActivateGroupByName { .. }
+            | Action::Sequence { .. } => bail!("'{action}' only gets a shortcut through a binding"),
+        }
+    }
+
+    pub fn get_hotkey(&self, action: &Action) -> Option<Hotkey> {
+        self.shortcut(action)?.hotkey
+    }
+
+    pub fn set_hotkey(&mut self, action: &Action, hotkey: Option<Hotkey>) -> anyhow::Result<()> {
+        self.shortcut_mut(action)?.hotkey = hotkey;
+        Ok(())
+    }
+
+    pub fn set_group_enabled(&mut self, group_id: Uuid, enabled: bool) {
+        self.group_mut(group_id).enabled = enabled;
+    }
+
+    /// Whether the group or binding the action belongs to is enabled
+    pub fn is_enabled(&self, action: &Action) -> bool {
+        match action {
+            Action::OpenGroup { group_id }
+            | Action::LaunchGroup { group_id }
+            | Action::RestoreHidden { group_id } => self.group(*group_id).is_none_or(|g| g.enabled),
+            Action::Binding { binding_id } => self.binding(*binding_id).is_none_or(|b| b.enabled),
+            _ => true,
+        }
+    }
+
+    pub fn set_exclusive(&mut self, group_id: Uuid, exclusive: bool) {
+        let group = self.group_mut(group_id);
+        group.exclusive = exclusive;
+    }
+
+    pub fn set_launch_stagger(&mut self, group_id: Uuid, stagger: Duration) {
+        let group = self.group_mut(group_id);
+        group.launch_stagger = stagger;
+    }
+
+    pub fn set_primary_member(&mut self, group_id: Uuid, member_id: Option<String>) {
+        let group = self.group_mut(group_id);
+        group.primary_member_id = member_id;
+    }
+
+    pub fn set_member_flags(&mut self, group_id: Uuid, member_id: &str, flags: MemberFlags) {
+        let group = self.group_mut(group_id);
+        group.set_flags(member_id, flags)
+    }
+
+    /// Re-evaluates the new rule against the last scan of the installed apps
+    pub fn set_rule(&mut self, group_id: Uuid, rule: Option<GroupRule>) {
+        let group = self.groups.iter_mut().find(|g| g.id() == group_id).unwrap();
+        group.rule = rule;
+        group.refresh(&self.installed_apps)
+    }
+
+    pub fn installed_apps(&self) -> &[(App, PathBuf)] {
+        &self.installed_apps
+    }
+
+    /// Keeps a new scan of the installed apps and re-evaluates the rules against it
+    pub fn set_installed_apps(&mut self, installed_apps: Vec<(App, PathBuf)>) {
+        self.installed_apps = installed_apps;
+        self.refresh_groups();
+    }
+
+    fn refresh_groups(&mut self) {
+        for group in &mut self.groups {
+            group.refresh(&self.installed_apps);
+        }
+    }
+
+    pub fn pin_member(&mut self, group_id: Uuid, member_id: &str) {
+        let group = self.group_mut(group_id);
+        group.pin(member_id)
+    }
+
+    pub fn exclude_member(&mut self, group_id: Uuid, member_id: &str) {
+        let group = self.group_mut(group_id);
+        group.exclude(member_id)
+    }
+
+    pub fn clear_exclusions(&mut self, group_id: Uuid) {
+        let group = self.groups.iter_mut().find(|g| g.id() == group_id).unwrap();
+        group.clear_exclusions();
+        group.refresh(&self.installed_apps)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use global_hotkey::hotkey::{Code, Modifiers};
+
+    use super::*;
+    use crate::models::{AppEntry, AppMatcher};
+
+    fn app(id: &str) -> Member {
+        Member::App(AppEntry::new(App::from(id.to_string())))
+    }
+
+    #[test]
+    fn set_parent_rejects_cycles() {
+        // Arrange
+        let mut config = Config::default();
+        let outer = config.add_group("Outer".to_string());
+        let inner = config.add_group("Inner".to_string());
+        config.set_parent(inner, Some(outer));
+
+        // Act
+        let moved_into_child = config.set_parent(outer, Some(inner));
+        let moved_into_self = config.set_parent(inner, Some(inner));
+
+        // Assert
+        assert!(!moved_into_child);
+        assert!(!moved_into_self);
+        assert_eq!(config.group(outer).unwrap().parent_id, None);
+        assert_eq!(config.group(inner).unwrap().parent_id, Some(outer));
+    }
+
+    #[test]
+    fn remove_group_moves_children_up() {
+        // Arrange
+        let mut config = Config::default();
+        let top = config.add_group("Top".to_string());
+        let middle = config.add_group("Middle".to_string());
+        let bottom = config.add_group("Bottom".to_string());
+        config.set_parent(middle, Some(top));
+        config.set_parent(bottom, Some(middle));
+
+        // Act
+        config.remove_group(middle);
+
+        // Assert
+        assert_eq!(config.group(bottom).unwrap().parent_id, Some(top));
+    }
+
+    #[test]
+    fn resolved_group_includes_descendants() {
+        // Arrange
+        let mut config = Config::default();
+        let work = config.add_group("Work".to_string());
+        let chat = config.add_group("Chat".to_string());
+        let mail = config.add_group("Mail".to_string());
+        config.set_parent(chat, Some(work));
+        config.set_parent(mail, Some(chat));
+        config.add_member(work, app("editor"));
+        config.add_member(chat, app("slack"));
+        config.add_member(mail, app("outlook"));
+        config.add_member(mail, app("editor"));
+        config.set_include_children(work, true);
+
+        // Act
+        let resolved = config.resolved_group(work).unwrap();
+
+        // Assert
+        let ids: Vec<String> = resolved.members().iter().map(|m| m.id()).collect();
+        assert_eq!(ids, vec!["editor", "slack", "outlook"]);
+    }
+
+    #[test]
+    fn resolved_groups_cover_every_group() {
+        // Arrange
+        let mut config = Config::default();
+        let work = config.add_group("Work".to_string());
+        let chat = config.add_group("Chat".to_string());
+        config.set_parent(chat, Some(work));
+        config.add_member(chat, app("slack"));
+        config.set_include_children(work, true);
+
+        // Act
+        let resolved = config.resolved_groups();
+
+        // Assert
+        let ids: Vec<Uuid> = resolved.iter().map(|g| g.id()).collect();
+        assert_eq!(ids, vec![work, chat]);
+        assert!(resolved.iter().all(|g| g.contains_app("slack")));
+    }
+
+    #[test]
+    fn resolved_group_keeps_flags_of_child_members() {
+        // Arrange
+        let mut config = Config::default();
+        let work = config.add_group("Work".to_string());
+        let chat = config.add_group("Chat".to_string());
+        config.set_parent(chat, Some(work));
+        config.add_member(work, app("editor"));
+        config.add_member(chat, app("slack"));
+        let flags = MemberFlags {
+            in_cycle: false,
+            ..MemberFlags::default()
+        };
+        config.set_member_flags(chat, "slack", flags);
+        config.set_include_children(work, true);
+
+        // Act
+        let resolved = config.resolved_group(work).unwrap();
+
+        // Assert
+        assert!(!resolved.flags("slack").in_cycle);
+        assert!(resolved.flags("editor").in_cycle);
+    }
+
+    fn include(config: &mut Config, group_id: Uuid, other_id: Uuid) {
+        config.add_member(group_id, Member::Group { group_id: other_id });
+    }
+
+    #[test]
+    fn resolved_group_expands_included_groups() {
+        // Arrange
+        let mut config = Config::default();
+        let browsers = config.add_group("Browsers".to_string());
+        let chat = config.add_group("Chat".to_string());
+        let frontend = config.add_group("Frontend".to_string());
+        config.add_member(browsers, app("firefox"));
+        config.add_member(chat, app("slack"));
+        config.add_member(frontend, app("editor"));
+        include(&mut config, frontend, browsers);
+        include(&mut config, browsers, chat);
+
+        // Act
+        config.add_member(chat, app("discord"));
+        let resolved = config.resolved_group(frontend).unwrap();
+
+        // Assert
+        let ids: Vec<String> = resolved.members().iter().map(|m| m.id()).collect();
+        assert_eq!(ids, vec!["editor", "firefox", "slack", "discord"]);
+    }
+
+    fn setup_shared_member(first_flags: bool) -> (Config, Uuid) {
+        let mut config = Config::default();
+        let first = config.add_group("First".to_string());
+        let second = config.add_group("Second".to_string());
+        let frontend = config.add_group("Frontend".to_string());
+        config.add_member(first, app("editor"));
+        config.add_member(second, app("editor"));
+        let flags = MemberFlags {
+            in_cycle: false,
+            ..MemberFlags::default()
+        };
+        let flagged = if first_flags { first } else { second };
+        config.set_member_flags(flagged, "editor", flags);
+        include(&mut config, frontend, first);
+        include(&mut config, frontend, second);
+        (config, frontend)
+    }
+
+    #[test]
+    fn resolved_group_prefers_explicit_flags() {
+        // Arrange
+        let (config, frontend) = setup_shared_member(true);
+        let (swapped_config, swapped_frontend) = setup_shared_member(false);
+
+        // Act
+        let resolved = config.resolved_group(frontend).unwrap();
+        let swapped = swapped_config.resolved_group(swapped_frontend).unwrap();
+
+        // Assert
+        assert!(!resolved.flags("editor").in_cycle);
+        assert!(!swapped.flags("editor").in_cycle);
+    }
+
+    #[test]
+    fn add_member_rejects_cyclic_include() {
+        // Arrange
+        let mut config = Config::default();
+        let a = config.add_group("A".to_string());
+        let b = config.add_group("B".to_string());
+        let c = config.add_group("C".to_string());
+        include(&mut config, a, b);
+        include(&mut config, b, c);
+
+        // Act
+        include(&mut config, c, a);
+        include(&mut config, a, a);
+
+        // Assert
+        assert_eq!(config.group(c).unwrap().referenced_groups().count(), 0);
+        assert_eq!(config.group(a).unwrap().referenced_groups().count(), 1);
+        assert!(!config.can_include(c, b));
+    }
+
+    #[test]
+    fn rename_and_remove_update_references() {
+        // Arrange
+        let mut config = Config::default();
+        let outer = config.add_group("Outer".to_string());
+        let inner = config.add_group("Inner".to_string());
+        include(&mut config, outer, inner);
+
+        // Act
+        config.set_name(inner, "Renamed".to_string());
+        let member = config.group(outer).unwrap().members()[0].clone();
+        let renamed = config.describe_member(&member);
+        config.remove_group(inner);
+
+        // Assert
+        assert_eq!(renamed, "Renamed");
+        assert!(config.group(outer).unwrap().members().is_empty());
+    }
+
+    #[test]
+    fn set_rule_uses_scanned_apps() {
+        // Arrange
+        let mut config = Config::default();
+        let group_id = config.add_group("IDEs".to_string());
+        let goland = App::from("com.jetbrains.goland".to_string());
+        config.set_installed_apps(vec![(goland, PathBuf::from("/Applications/GoLand.app"))]);
+        let rule = GroupRule::Apps(AppMatcher::glob("com.jetbrains.*"));
+
+        // Act
+        config.set_rule(group_id, Some(rule));
+
+        // Assert
+        let group = config.group(group_id).unwrap();
+        assert_eq!(group.computed_members(), &vec![app("com.jetbrains.goland")]);
+    }
+
+    #[test]
+    fn set_hotkey_only_on_shortcuts() {
+        // Arrange
+        let mut config = Config::default();
+        let group_id = config.add_group("Browsers".to_string());
+        let hotkey = Hotkey::new(Modifiers::SUPER, Code::KeyB);
+        let general = Action::OpenUrl {
+            url: "https://example.com".to_string(),
+        };
+
+        // Act
+        let group_result = config.set_hotkey(&Action::OpenGroup { group_id }, Some(hotkey));
+        let general_result = config.set_hotkey(&general, Some(hotkey));
+
+        // Assert
+        assert!(group_result.is_ok());
+        assert!(general_result.is_err());
+        assert_eq!(
+            config.get_hotkey(&Action::OpenGroup { group_id }),
+            Some(hotkey)
+        );
+        assert_eq!(config.get_hotkey(&general), None);
     }
 }
@@ -0,0 +1,49 @@
+/// Where a hotkey runs its action, by the id of the frontmost app.
+/// Lets app shortcuts which collide with a hotkey win inside that app.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HotkeyContext {
+    /// Only active while one of these apps is frontmost, anywhere if empty
+    pub only_in: Vec<String>,
+    /// Inactive while one of these apps is frontmost
+    pub excluded: Vec<String>,
+}
+
+impl HotkeyContext {
+    pub fn is_empty(&self) -> bool {
+        self.only_in.is_empty() && self.excluded.is_empty()
+    }
+
+    pub fn allows(&self, frontmost_app: Option<&str>) -> bool {
+        let listed = |apps: &[String]| {
+            frontmost_app.is_some_and(|f| apps.iter().any(|a| a.eq_ignore_ascii_case(f)))
+        };
+        !listed(&self.excluded) && (self.only_in.is_empty() || listed(&self.only_in))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn allows_by_frontmost_app() {
+        // Arrange
+        let context = HotkeyContext {
+            only_in: vec!["browser".to_string(), "editor".to_string()],
+            excluded: vec!["Editor".to_string()],
+        };
+
+        // Act
+        let browser = context.allows(Some("Browser"));
+        let editor = context.allows(Some("editor"));
+        let chat = context.allows(Some("chat"));
+        let unknown = context.allows(None);
+
+        // Assert
+        assert!(browser);
+        assert!(!editor);
+        assert!(!chat);
+        assert!(!unknown);
+        assert!(HotkeyContext::default().allows(None));
+    }
+}
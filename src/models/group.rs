@@ -1,17 +1,80 @@
+use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
+use std::path::PathBuf;
+use std::time::Duration;
 
 use uuid::Uuid;
 
-use crate::models::Hotkey;
 use crate::models::traits::Identifiable;
+use crate::models::{AppEntry, GroupRule, LaunchOptions, Member, Shortcut, WindowPolicy};
 use crate::os::App;
 
+/// What the group hotkey does when one of its apps is already in front
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum GroupMode {
+    #[default]
+    Cycle,
+    Toggle,
+}
+
+impl GroupMode {
+    pub fn all() -> [Self; 2] {
+        [GroupMode::Cycle, GroupMode::Toggle]
+    }
+}
+
+impl Display for GroupMode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let msg = match self {
+            GroupMode::Cycle => "Cycle",
+            GroupMode::Toggle => "Toggle",
+        };
+        write!(f, "{msg}")
+    }
+}
+
+/// How a member takes part in its group
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MemberFlags {
+    /// The group hotkey may land on it
+    pub in_cycle: bool,
+    /// Opened when launching the whole group
+    pub in_launch_all: bool,
+}
+
+impl Default for MemberFlags {
+    fn default() -> Self {
+        Self {
+            in_cycle: true,
+            in_launch_all: true,
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct Group {
     id: Uuid,
     pub name: String,
-    pub hotkey: Option<Hotkey>,
-    apps: Vec<App>,
+    pub shortcut: Shortcut,
+    pub mode: GroupMode,
+    pub window_policy: WindowPolicy,
+    pub launch_shortcut: Shortcut,
+    pub launch_stagger: Duration,
+    pub primary_member_id: Option<String>,
+    pub exclusive: bool,
+    pub restore_shortcut: Shortcut,
+    /// Makes the group dynamic, its rule adds the matching installed apps
+    pub rule: Option<GroupRule>,
+    /// The group this one is nested in, shown as a folder in the sidebar
+    pub parent_id: Option<Uuid>,
+    /// Cycles through the apps of nested groups too
+    pub include_children: bool,
+    /// Disabled groups keep their hotkeys reserved, but they aren't registered
+    pub enabled: bool,
+    members: Vec<Member>,
+    computed: Vec<Member>, // rule results, refreshed from the app catalog
+    excluded: Vec<String>,
+    member_flags: HashMap<String, MemberFlags>, // by lowercase member id, like members are matched
 }
 
 impl Identifiable<Uuid> for Group {
@@ -25,21 +88,220 @@ impl Group {
         Self {
             id: Uuid::new_v4(),
             name,
-            hotkey: None,
-            apps: Vec::new(),
+            shortcut: Shortcut::default(),
+            mode: GroupMode::default(),
+            window_policy: WindowPolicy::default(),
+            launch_shortcut: Shortcut::default(),
+            launch_stagger: Duration::ZERO,
+            primary_member_id: None,
+            exclusive: false,
+            restore_shortcut: Shortcut::default(),
+            rule: None,
+            parent_id: None,
+            include_children: false,
+            enabled: true,
+            members: Vec::new(),
+            computed: Vec::new(),
+            excluded: Vec::new(),
+            member_flags: HashMap::new(),
+        }
+    }
+
+    pub fn members(&self) -> &Vec<Member> {
+        &self.members
+    }
+
+    /// Members added by the rule, without the pinned and excluded ones
+    pub fn computed_members(&self) -> &Vec<Member> {
+        &self.computed
+    }
+
+    pub fn excluded(&self) -> &Vec<String> {
+        &self.excluded
+    }
+
+    /// Picked members followed by the computed ones
+    pub fn all_members(&self) -> impl Iterator<Item = &Member> {
+        self.members.iter().chain(&self.computed)
+    }
+
+    pub fn flags(&self, member_id: &str) -> MemberFlags {
+        self.explicit_flags(member_id).unwrap_or_default()
+    }
+
+    /// The flags set for the member, None if it keeps the defaults
+    pub fn explicit_flags(&self, member_id: &str) -> Option<MemberFlags> {
+        self.member_flags.get(&flags_key(member_id)).copied()
+    }
+
+    pub fn member(&self, member_id: &str) -> Option<&Member> {
+        self.all_members()
+            .find(|m| m.id().eq_ignore_ascii_case(member_id))
+    }
+
+    pub fn contains_app(&self, app_id: &str) -> bool {
+        self.all_members().any(|m| match m {
+            Member::Matcher(matcher) => matcher.matches(app_id),
+            m => m
+                .app()
+                .is_some_and(|app| app.id().eq_ignore_ascii_case(app_id)),
+        })
+    }
+
+    /// The member in front: a matching window rule wins over the whole app, which wins over
+    /// matchers
+    pub fn current_member(&self, app_id: &str, title: Option<&str>) -> Option<&Member> {
+        let window_member = self.all_members().find(|m| match m {
+            Member::Window { app, pattern } => {
+                app.id().eq_ignore_ascii_case(app_id) && title.is_some_and(|t| pattern.matches(t))
+            }
+            _ => false,
+        });
+        window_member
+            .or_else(|| self.member(app_id).filter(|m| m.as_app().is_some()))
+            .or_else(|| {
+                self.all_members().find(|m| match m {
+                    Member::Matcher(matcher) => matcher.matches(app_id),
+                    _ => false,
+                })
+            })
+    }
+
+    /// Members in the order they should be tried: starting after the current one, or at the
+    /// primary member, running apps first. Other members can't be running, so they are always
+    /// tried in cycle order. Members left out of cycling are skipped.
+    pub fn activation_order(
+        &self,
+        current_member_id: Option<&str>,
+        is_running: impl Fn(&App) -> bool,
+    ) -> Vec<&Member> {
+        let mut ordered: Vec<&Member> = self
+            .all_members()
+            .filter(|m| self.flags(&m.id()).in_cycle)
+            .collect();
+        let position = |id: &str| ordered.iter().position(|m| m.id().eq_ignore_ascii_case(id));
+        let start = match current_member_id {
+            Some(id) => position(id).map_or(0, |i| i + 1),
+            None => self
+                .primary_member_id
+                .as_deref()
+                .and_then(position)
+                .unwrap_or(0),
+        };
+        ordered.rotate_left(start);
+        // stable, so the cycle order is kept
+        ordered.sort_by_key(|m| m.app().is_some_and(|app| !is_running(app)));
+        ordered
+    }
+
+    /// Members opened when launching the whole group
+    pub fn launch_members(&self) -> impl Iterator<Item = &Member> {
+        self.all_members()
+            .filter(|m| self.flags(&m.id()).in_launch_all)
+    }
+
+    /// The member brought to front after launching the whole group, defaults to the first one
+    pub fn primary_member(&self) -> Option<&Member> {
+        self.primary_member_id
+            .as_deref()
+            .and_then(|id| self.member(id))
+            .or_else(|| self.all_members().next())
+    }
+
+    pub(super) fn add_member(&mut self, member: Member) {
+        if self.member(&member.id()).is_none() {
+            self.members.push(member);
+        }
+    }
+
+    /// Puts the member in front of `before_id`, or last without one
+    pub(super) fn move_member(&mut self, member_id: &str, before_id: Option<&str>) {
+        let Some(from) = self.members.iter().position(|m| m.id() == member_id) else {
+            return;
+        };
+        let member = self.members.remove(from);
+        let to = before_id
+            .and_then(|id| self.members.iter().position(|m| m.id() == id))
+            .unwrap_or(self.members.len());
+        self.members.insert(to, member);
+    }
+
+    pub(super) fn set_launch_options(&mut self, app_id: &str, launch_options: LaunchOptions) {
+        let entry = self.members.iter_mut().find_map(|m| match m {
+            Member::App(entry) if entry.id() == app_id => Some(entry),
+            _ => None,
+        });
+        if let Some(entry) = entry {
+            entry.launch_options = launch_options;
         }
     }
 
-    pub fn apps(&self) -> &Vec<App> {
-        &self.apps
+    pub(super) fn remove_member(&mut self, member_id: String) {
+        if self.primary_member_id.as_ref() == Some(&member_id) {
+            self.primary_member_id = None;
+        }
+        self.member_flags.remove(&flags_key(&member_id));
+        self.members.retain(|m| m.id() != member_id)
     }
 
-    pub(super) fn add_app(&mut self, app: App) {
-        self.apps.push(app);
+    pub(super) fn set_flags(&mut self, member_id: &str, flags: MemberFlags) {
+        self.member_flags.insert(flags_key(member_id), flags);
     }
 
-    pub(super) fn remove_app(&mut self, app_id: String) {
-        self.apps.retain(|a| a.id() != app_id)
+    /// Keeps flags of members from included groups, unless this group sets its own
+    pub(super) fn inherit_flags(&mut self, member_id: &str, flags: MemberFlags) {
+        self.member_flags
+            .entry(flags_key(member_id))
+            .or_insert(flags);
+    }
+
+    pub(super) fn clear_members(&mut self) {
+        self.members.clear();
+        self.computed.clear();
+    }
+
+    /// Ids of the groups this one includes directly
+    pub fn referenced_groups(&self) -> impl Iterator<Item = Uuid> {
+        self.members.iter().filter_map(|m| match m {
+            Member::Group { group_id } => Some(*group_id),
+            _ => None,
+        })
+    }
+
+    /// Re-evaluates the rule against the installed apps
+    pub(super) fn refresh(&mut self, installed_apps: &[(App, PathBuf)]) {
+        let Some(rule) = &self.rule else {
+            self.computed.clear();
+            return;
+        };
+        self.computed = installed_apps
+            .iter()
+            .filter(|(app, path)| rule.matches(app, path))
+            .map(|(app, _)| Member::App(AppEntry::new(app.clone())))
+            .filter(|m| {
+                let id = m.id();
+                !self.excluded.contains(&id) && !self.members.iter().any(|p| p.id() == id)
+            })
+            .collect();
+    }
+
+    /// Keeps a computed member even when the rule stops matching it
+    pub(super) fn pin(&mut self, member_id: &str) {
+        if let Some(i) = self.computed.iter().position(|m| m.id() == member_id) {
+            let member = self.computed.remove(i);
+            self.members.push(member);
+        }
+    }
+
+    pub(super) fn exclude(&mut self, member_id: &str) {
+        self.computed.retain(|m| m.id() != member_id);
+        if !self.excluded.iter().any(|id| id == member_id) {
+            self.excluded.push(member_id.to_string());
+        }
+    }
+
+    pub(super) fn clear_exclusions(&mut self) {
+        self.excluded.clear();
     }
 }
 
@@ -48,3 +310,262 @@ impl Display for Group {
         write!(f, "{}", self.name)
     }
 }
+
+fn flags_key(member_id: &str) -> String {
+    member_id.to_ascii_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{AppMatcher, TitlePattern};
+
+    fn setup_group(app_ids: &[&str]) -> Group {
+        let mut group = Group::new("Test".to_string());
+        for id in app_ids {
+            group.add_member(Member::App(AppEntry::new(App::from(id.to_string()))));
+        }
+        group
+    }
+
+    fn ids(members: Vec<&Member>) -> Vec<String> {
+        members.iter().map(|m| m.id()).collect()
+    }
+
+    #[test]
+    fn activation_order_from_start() {
+        // Arrange
+        let group = setup_group(&["a", "b", "c"]);
+
+        // Act
+        let order = group.activation_order(None, |_| true);
+
+        // Assert
+        assert_eq!(ids(order), vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn activation_order_cycles_after_current() {
+        // Arrange
+        let group = setup_group(&["a", "b", "c"]);
+
+        // Act
+        let order = group.activation_order(Some("B"), |_| true);
+
+        // Assert
+        assert_eq!(ids(order), vec!["c", "a", "b"]);
+    }
+
+    #[test]
+    fn activation_order_prefers_running() {
+        // Arrange
+        let group = setup_group(&["a", "b", "c", "d"]);
+
+        // Act
+        let order = group.activation_order(Some("a"), |a| a.id() != "b");
+
+        // Assert
+        assert_eq!(ids(order), vec!["c", "d", "a", "b"]);
+    }
+
+    #[test]
+    fn activation_order_cycles_through_other_members() {
+        // Arrange
+        let mut group = setup_group(&["a", "b"]);
+        group.add_member(Member::Url("https://example.com".to_string()));
+
+        // Act
+        let order = group.activation_order(Some("b"), |a| a.id() == "a");
+
+        // Assert
+        assert_eq!(ids(order), vec!["https://example.com", "a", "b"]);
+    }
+
+    #[test]
+    fn activation_order_unknown_current() {
+        // Arrange
+        let group = setup_group(&["a", "b"]);
+
+        // Act
+        let order = group.activation_order(Some("z"), |_| false);
+
+        // Assert
+        assert_eq!(ids(order), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn move_member_before_other() {
+        // Arrange
+        let mut group = setup_group(&["a", "b", "c"]);
+
+        // Act
+        group.move_member("c", Some("a"));
+        let before = ids(group.members().iter().collect());
+        group.move_member("c", None);
+        let last = ids(group.members().iter().collect());
+
+        // Assert
+        assert_eq!(before, vec!["c", "a", "b"]);
+        assert_eq!(last, vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn current_member_prefers_matching_window() {
+        // Arrange
+        let mut group = setup_group(&["browser"]);
+        group.add_member(Member::Window {
+            app: App::from("browser".to_string()),
+            pattern: TitlePattern::Substring("grafana".to_string()),
+        });
+
+        // Act
+        let grafana = group.current_member("browser", Some("Grafana - Browser"));
+        let other = group.current_member("browser", Some("Inbox - Browser"));
+
+        // Assert
+        assert_eq!(
+            grafana.map(|m| m.id()),
+            Some("browser#*grafana*".to_string())
+        );
+        assert_eq!(other.map(|m| m.id()), Some("browser".to_string()));
+    }
+
+    #[test]
+    fn current_member_falls_back_to_matcher() {
+        // Arrange
+        let mut group = setup_group(&["com.jetbrains.goland"]);
+        group.add_member(Member::Matcher(AppMatcher::glob("com.jetbrains.*")));
+
+        // Act
+        let exact = group.current_member("com.jetbrains.goland", None);
+        let matched = group.current_member("com.jetbrains.rustrover", None);
+
+        // Assert
+        assert_eq!(
+            exact.map(|m| m.id()),
+            Some("com.jetbrains.goland".to_string())
+        );
+        assert_eq!(matched.map(|m| m.id()), Some("com.jetbrains.*".to_string()));
+        assert!(group.contains_app("com.jetbrains.rustrover"));
+        assert!(!group.contains_app("com.apple.Safari"));
+    }
+
+    #[test]
+    fn refresh_skips_picked_and_excluded() {
+        // Arrange
+        let mut group = setup_group(&["com.jetbrains.goland"]);
+        group.rule = Some(GroupRule::Apps(AppMatcher::glob("com.jetbrains.*")));
+        group.exclude("com.jetbrains.fleet");
+        let installed: Vec<(App, PathBuf)> = ["goland", "rustrover", "fleet", "toolbox"]
+            .iter()
+            .map(|name| {
+                let app = App::from(format!("com.jetbrains.{name}"));
+                (app, PathBuf::from(format!("/Applications/{name}.app")))
+            })
+            .collect();
+
+        // Act
+        group.refresh(&installed);
+
+        // Assert
+        let computed: Vec<&Member> = group.computed_members().iter().collect();
+        assert_eq!(
+            ids(computed),
+            vec!["com.jetbrains.rustrover", "com.jetbrains.toolbox"]
+        );
+    }
+
+    #[test]
+    fn pin_moves_computed_member() {
+        // Arrange
+        let mut group = setup_group(&["a"]);
+        group.rule = Some(GroupRule::Apps(AppMatcher::glob("*")));
+        group.refresh(&[(App::from("b".to_string()), PathBuf::from("/b"))]);
+
+        // Act
+        group.pin("b");
+        group.refresh(&[]);
+
+        // Assert
+        assert_eq!(ids(group.members().iter().collect()), vec!["a", "b"]);
+        assert!(group.computed_members().is_empty());
+    }
+
+    #[test]
+    fn activation_order_includes_computed() {
+        // Arrange
+        let mut group = setup_group(&["a"]);
+        group.rule = Some(GroupRule::Apps(AppMatcher::glob("*")));
+        group.refresh(&[(App::from("b".to_string()), PathBuf::from("/b"))]);
+
+        // Act
+        let order = group.activation_order(Some("b"), |_| true);
+
+        // Assert
+        assert_eq!(ids(order), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn activation_order_skips_members_out_of_cycle() {
+        // Arrange
+        let mut group = setup_group(&["editor", "music", "browser"]);
+        let flags = MemberFlags {
+            in_cycle: false,
+            ..MemberFlags::default()
+        };
+        group.set_flags("music", flags);
+
+        // Act
+        let order = group.activation_order(Some("editor"), |_| true);
+
+        // Assert
+        assert_eq!(ids(order), vec!["browser", "editor"]);
+    }
+
+    #[test]
+    fn activation_order_starts_at_primary() {
+        // Arrange
+        let mut group = setup_group(&["a", "b", "c"]);
+        group.primary_member_id = Some("b".to_string());
+
+        // Act
+        let order = group.activation_order(None, |_| true);
+
+        // Assert
+        assert_eq!(ids(order), vec!["b", "c", "a"]);
+    }
+
+    #[test]
+    fn launch_members_honours_flags() {
+        // Arrange
+        let mut group = setup_group(&["a", "b"]);
+        let flags = MemberFlags {
+            in_launch_all: false,
+            ..MemberFlags::default()
+        };
+        group.set_flags("b", flags);
+
+        // Act
+        let launched: Vec<&Member> = group.launch_members().collect();
+
+        // Assert
+        assert_eq!(ids(launched), vec!["a"]);
+    }
+
+    #[test]
+    fn flags_ignore_case() {
+        // Arrange
+        let mut group = setup_group(&["Editor"]);
+        let flags = MemberFlags {
+            in_cycle: false,
+            ..MemberFlags::default()
+        };
+
+        // Act
+        group.set_flags("editor", flags);
+
+        // Assert
+        assert_eq!(group.flags("EDITOR"), flags);
+        assert!(ids(group.activation_order(None, |_| true)).is_empty());
+    }
+}
@@ -0,0 +1,228 @@
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::iter::Peekable;
+use std::str::Chars;
+
+use regex::{Regex, RegexBuilder};
+
+use crate::models::Identifiable;
+use crate::os::App;
+
+/// A pattern compiled once when it is set, compared by the text it was written as
+#[derive(Debug, Clone)]
+pub struct CompiledRegex {
+    source: String,
+    regex: Option<Regex>, // invalid patterns are rejected in the editor
+}
+
+impl CompiledRegex {
+    pub fn new(source: String, pattern: &str, case_insensitive: bool) -> Self {
+        let regex = RegexBuilder::new(pattern)
+            .case_insensitive(case_insensitive)
+            .build()
+            .ok();
+        Self { source, regex }
+    }
+
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// An invalid pattern never matches
+    pub fn is_match(&self, text: &str) -> bool {
+        self.regex.as_ref().is_some_and(|r| r.is_match(text))
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.regex.is_some()
+    }
+}
+
+impl PartialEq for CompiledRegex {
+    fn eq(&self, other: &Self) -> bool {
+        self.source == other.source
+    }
+}
+
+impl Eq for CompiledRegex {}
+
+/// Matches app identifiers (bundle ids or exe paths), ignoring case
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AppMatcher {
+    /// `*` matches any run of characters, `?` a single one
+    Glob(CompiledRegex),
+    Regex(CompiledRegex),
+}
+
+impl AppMatcher {
+    pub fn glob(glob: impl Into<String>) -> Self {
+        let glob = glob.into();
+        let pattern = glob_to_regex(&glob);
+        AppMatcher::Glob(CompiledRegex::new(glob, &pattern, true))
+    }
+
+    pub fn regex(re: impl Into<String>) -> Self {
+        let re = re.into();
+        let pattern = re.clone();
+        AppMatcher::Regex(CompiledRegex::new(re, &pattern, true))
+    }
+
+    pub fn matches(&self, app_id: &str) -> bool {
+        self.compiled().is_match(app_id)
+    }
+
+    pub fn is_valid(&self) -> bool {
+        !self.compiled().source().is_empty() && self.compiled().is_valid()
+    }
+
+    /// The app to open out of `apps`, the last match so newer versioned installs win
+    pub fn resolve(&self, apps: &[App]) -> Option<App> {
+        apps.iter()
+            .filter(|app| self.matches(&app.id()))
+            .max_by(|a, b| natural_cmp(&a.id(), &b.id()))
+            .cloned()
+    }
+
+    fn compiled(&self) -> &CompiledRegex {
+        match self {
+            AppMatcher::Glob(glob) => glob,
+            AppMatcher::Regex(re) => re,
+        }
+    }
+}
+
+/// Compares runs of digits by their value, so `app-1.0.10` comes after `app-1.0.9`
+fn natural_cmp(a: &str, b: &str) -> Ordering {
+    let (mut a, mut b) = (a.chars().peekable(), b.chars().peekable());
+    loop {
+        let order = match (a.peek().copied(), b.peek().copied()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(x), Some(y)) if x.is_ascii_digit() && y.is_ascii_digit() => {
+                let (x, y) = (take_number(&mut a), take_number(&mut b));
+                x.len().cmp(&y.len()).then_with(|| x.cmp(&y))
+            }
+            (Some(x), Some(y)) => {
+                a.next();
+                b.next();
+                x.to_ascii_lowercase().cmp(&y.to_ascii_lowercase())
+            }
+        };
+        if order != Ordering::Equal {
+            return order;
+        }
+    }
+}
+
+/// The digits up next without leading zeros, which compare by length and then by text
+fn take_number(chars: &mut Peekable<Chars>) -> String {
+    let mut digits = String::new();
+    while let Some(c) = chars.next_if(char::is_ascii_digit) {
+        digits.push(c);
+    }
+    digits.trim_start_matches('0').to_string()
+}
+
+fn glob_to_regex(glob: &str) -> String {
+    let mut pattern = "^".to_string();
+    for c in glob.chars() {
+        match c {
+            '*' => pattern.push_str(".*"),
+            '?' => pattern.push('.'),
+            c => pattern.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+    pattern + "$"
+}
+
+impl Display for AppMatcher {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AppMatcher::Glob(glob) => write!(f, "{}", glob.source()),
+            AppMatcher::Regex(re) => write!(f, "/{}/", re.source()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apps(ids: &[&str]) -> Vec<App> {
+        ids.iter().map(|id| App::from(id.to_string())).collect()
+    }
+
+    #[test]
+    fn glob_matches_versioned_folder() {
+        // Arrange
+        let matcher = AppMatcher::glob(r"*\Discord\app-*\discord.exe");
+
+        // Act & Assert
+        assert!(matcher.matches(r"C:\Users\me\AppData\Local\Discord\app-1.0.9\Discord.exe"));
+        assert!(!matcher.matches(r"C:\Users\me\AppData\Local\Discord\Update.exe"));
+    }
+
+    #[test]
+    fn glob_escapes_regex_characters() {
+        // Arrange
+        let matcher = AppMatcher::glob("com.jetbrains.*");
+
+        // Act & Assert
+        assert!(matcher.matches("com.jetbrains.goland"));
+        assert!(!matcher.matches("comXjetbrains.goland"));
+    }
+
+    #[test]
+    fn regex_matches_family() {
+        // Arrange
+        let matcher = AppMatcher::regex(r"^com\.microsoft\.(word|excel)$");
+
+        // Act & Assert
+        assert!(matcher.matches("com.microsoft.Word"));
+        assert!(!matcher.matches("com.microsoft.teams"));
+    }
+
+    #[test]
+    fn invalid_regex_never_matches() {
+        // Arrange
+        let matcher = AppMatcher::regex("(unclosed");
+
+        // Act & Assert
+        assert!(!matcher.matches("(unclosed"));
+        assert!(!matcher.is_valid());
+    }
+
+    #[test]
+    fn resolve_prefers_newest() {
+        // Arrange
+        let matcher = AppMatcher::glob(r"*\app-*\discord.exe");
+        let apps = apps(&[
+            r"C:\Discord\app-1.0.8\Discord.exe",
+            r"C:\Discord\app-1.0.9\Discord.exe",
+            r"C:\Slack\Slack.exe",
+        ]);
+
+        // Act
+        let resolved = matcher.resolve(&apps);
+
+        // Assert
+        assert_eq!(resolved.map(|a| a.id()), Some(apps[1].id()));
+    }
+
+    #[test]
+    fn resolve_compares_versions_by_number() {
+        // Arrange
+        let matcher = AppMatcher::glob(r"*\app-*\discord.exe");
+        let apps = apps(&[
+            r"C:\Discord\app-1.0.10\Discord.exe",
+            r"C:\Discord\app-1.0.9\Discord.exe",
+        ]);
+
+        // Act
+        let resolved = matcher.resolve(&apps);
+
+        // Assert
+        assert_eq!(resolved.map(|a| a.id()), Some(apps[0].id()));
+    }
+}
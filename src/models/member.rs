@@ -0,0 +1,180 @@
+use std::fmt::{Display, Formatter};
+use std::path::PathBuf;
+
+use anyhow::anyhow;
+use uuid::Uuid;
+
+use crate::models::{
+    AppEntry, AppMatcher, Identifiable, LaunchOptions, TitlePattern, WindowPolicy,
+};
+use crate::os::{App, OpenError, Openable, Shell, ShellBehavior, WindowManager, WindowModel};
+
+/// Anything a group can open
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Member {
+    App(AppEntry),
+    /// Only the app's windows whose title matches
+    Window {
+        app: App,
+        pattern: TitlePattern,
+    },
+    /// Whichever app matches when the group is activated
+    Matcher(AppMatcher),
+    /// Another group's members, expanded when activating
+    Group {
+        group_id: Uuid,
+    },
+    Url(String),
+    /// Whether it is a folder is kept from when it was picked
+    Path {
+        path: PathBuf,
+        is_dir: bool,
+    },
+    Command(String),
+}
+
+/// What kind of member to add, as offered in the member list
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MemberKind {
+    App,
+    Window,
+    Matcher,
+    Group,
+    Url,
+    File,
+    Folder,
+    Command,
+}
+
+impl MemberKind {
+    pub fn all() -> [Self; 8] {
+        [
+            Self::App,
+            Self::Window,
+            Self::Matcher,
+            Self::Group,
+            Self::Url,
+            Self::File,
+            Self::Folder,
+            Self::Command,
+        ]
+    }
+}
+
+impl Display for MemberKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let msg = match self {
+            MemberKind::App => "App",
+            MemberKind::Window => "Window",
+            MemberKind::Matcher => "Matcher",
+            MemberKind::Group => "Group",
+            MemberKind::Url => "URL",
+            MemberKind::File => "File",
+            MemberKind::Folder => "Folder",
+            MemberKind::Command => "Command",
+        };
+        write!(f, "{msg}")
+    }
+}
+
+impl Member {
+    /// Matchers which match no running app pick one of `installed_apps`
+    pub fn open(
+        &self,
+        window_policy: &WindowPolicy,
+        installed_apps: &[(App, PathBuf)],
+    ) -> Result<(), OpenError> {
+        match self {
+            Member::App(entry) => entry.open(window_policy),
+            Member::Window { app, pattern } => app.open_window(window_policy, pattern),
+            Member::Matcher(matcher) => {
+                let app = resolve(matcher, installed_apps)?;
+                app.open(window_policy, &LaunchOptions::default())
+            }
+            Member::Url(url) => Ok(Shell::open_url(url)?),
+            Member::Path { path, .. } => Shell::open_path(path),
+            Member::Command(command) => Ok(Shell::run_command(command)?),
+            Member::Group { group_id } => Err(OpenError::Failed(anyhow!(
+                "Group '{group_id}' should have been expanded"
+            ))),
+        }
+    }
+
+    pub fn as_app(&self) -> Option<&AppEntry> {
+        match self {
+            Member::App(entry) => Some(entry),
+            _ => None,
+        }
+    }
+
+    /// The app whose windows this member brings to front
+    pub fn app(&self) -> Option<&App> {
+        match self {
+            Member::App(entry) => Some(&entry.app),
+            Member::Window { app, .. } => Some(app),
+            _ => None,
+        }
+    }
+
+    pub fn kind(&self) -> MemberKind {
+        match self {
+            Member::App(_) => MemberKind::App,
+            Member::Window { .. } => MemberKind::Window,
+            Member::Matcher(_) => MemberKind::Matcher,
+            Member::Group { .. } => MemberKind::Group,
+            Member::Url(_) => MemberKind::Url,
+            Member::Path { is_dir: true, .. } => MemberKind::Folder,
+            Member::Path { .. } => MemberKind::File,
+            Member::Command(_) => MemberKind::Command,
+        }
+    }
+}
+
+/// Running apps win over installed ones
+fn resolve(matcher: &AppMatcher, installed_apps: &[(App, PathBuf)]) -> Result<App, OpenError> {
+    let running: Vec<App> = WindowManager
+        .running_apps()?
+        .into_iter()
+        .map(App::from)
+        .collect();
+    if let Some(app) = matcher.resolve(&running) {
+        return Ok(app);
+    }
+    let installed: Vec<App> = installed_apps.iter().map(|(app, _)| app.clone()).collect();
+    matcher
+        .resolve(&installed)
+        .ok_or_else(|| OpenError::NotInstalled {
+            app: matcher.to_string(),
+        })
+}
+
+impl Identifiable<String> for Member {
+    fn id(&self) -> String {
+        match self {
+            Member::App(entry) => entry.id(),
+            Member::Window { app, pattern } => format!("{}#{pattern}", app.id()),
+            Member::Matcher(matcher) => matcher.to_string(),
+            Member::Group { group_id } => group_id.to_string(),
+            Member::Url(url) => url.clone(),
+            Member::Path { path, .. } => path.to_string_lossy().into_owned(),
+            Member::Command(command) => command.clone(),
+        }
+    }
+}
+
+impl Display for Member {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Member::App(entry) => write!(f, "{entry}"),
+            Member::Window { app, pattern } => write!(f, "{app}: {pattern}"),
+            Member::Matcher(matcher) => write!(f, "{matcher}"),
+            Member::Group { group_id } => write!(f, "{group_id}"),
+            Member::Url(url) => write!(f, "{url}"),
+            Member::Path { path, .. } => {
+                let name = path.file_name().unwrap_or(path.as_os_str());
+                write!(f, "{}", name.to_string_lossy())
+            }
+            Member::Command(command) => write!(f, "{command}"),
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+
+use crate::models::{AppMatcher, Identifiable};
+use crate::os::App;
+use crate::util::expand_home;
+
+/// Computes the members of a dynamic group from the installed apps
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GroupRule {
+    /// Apps whose identifier matches, e.g. all JetBrains IDEs
+    Apps(AppMatcher),
+    /// Apps installed somewhere below a folder, `~` is expanded
+    InstalledIn(PathBuf),
+}
+
+impl GroupRule {
+    pub fn matches(&self, app: &App, install_path: &Path) -> bool {
+        match self {
+            GroupRule::Apps(matcher) => matcher.matches(&app.id()),
+            GroupRule::InstalledIn(folder) => {
+                let folder = expand_home(&folder.to_string_lossy());
+                install_path.starts_with(folder)
+            }
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        match self {
+            GroupRule::Apps(matcher) => matcher.is_valid(),
+            GroupRule::InstalledIn(folder) => !folder.as_os_str().is_empty(),
+        }
+    }
+}
+
+impl Display for GroupRule {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GroupRule::Apps(matcher) => write!(f, "Apps matching {matcher}"),
+            GroupRule::InstalledIn(folder) => write!(f, "Apps in {}", folder.display()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn installed_in_matches_subfolders() {
+        // Arrange
+        let rule = GroupRule::InstalledIn(PathBuf::from("/Applications/Games"));
+        let app = App::from("com.example.game".to_string());
+
+        // Act & Assert
+        assert!(rule.matches(&app, Path::new("/Applications/Games/Strategy/Game.app")));
+        assert!(!rule.matches(&app, Path::new("/Applications/GamesOld/Game.app")));
+    }
+
+    #[test]
+    fn apps_rule_ignores_install_path() {
+        // Arrange
+        let rule = GroupRule::Apps(AppMatcher::glob("com.jetbrains.*"));
+
+        // Act & Assert
+        let goland = App::from("com.jetbrains.goland".to_string());
+        assert!(rule.matches(&goland, Path::new("/Applications/GoLand.app")));
+        let safari = App::from("com.apple.Safari".to_string());
+        assert!(!rule.matches(&safari, Path::new("/Applications/Safari.app")));
+    }
+}
@@ -0,0 +1,19 @@
+use crate::models::{Action, Bindable, Shortcut};
+
+/// App-wide settings which are not tied to a group
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct Settings {
+    pub previous_group_shortcut: Shortcut,
+    pub pause_shortcut: Shortcut,
+    /// Only the pause hotkey stays registered meanwhile
+    pub hotkeys_paused: bool,
+}
+
+impl Bindable for Settings {
+    fn bindings(&self) -> Vec<(&Shortcut, Action)> {
+        vec![
+            (&self.previous_group_shortcut, Action::PreviousGroup),
+            (&self.pause_shortcut, Action::ToggleHotkeysPaused),
+        ]
+    }
+}
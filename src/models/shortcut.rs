@@ -0,0 +1,13 @@
+use crate::models::{Chord, Hotkey, HotkeyContext, Trigger};
+
+/// The keys which run one action of a group, a binding or the settings
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct Shortcut {
+    pub hotkey: Option<Hotkey>,
+    /// Typed instead of the hotkey, both may be set
+    pub chord: Option<Chord>,
+    /// How the hotkey has to be pressed, other actions may share it with another trigger
+    pub trigger: Trigger,
+    /// The apps in front of which the hotkey is registered
+    pub context: HotkeyContext,
+}
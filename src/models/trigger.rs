@@ -0,0 +1,27 @@
+use std::fmt::{Display, Formatter};
+
+/// How a hotkey has to be pressed to run an action, so one hotkey can run several
+#[derive(Debug, Clone, Copy, Default, Hash, PartialEq, Eq)]
+pub enum Trigger {
+    #[default]
+    Tap,
+    DoubleTap,
+    Hold,
+}
+
+impl Trigger {
+    pub fn all() -> [Self; 3] {
+        [Trigger::Tap, Trigger::DoubleTap, Trigger::Hold]
+    }
+}
+
+impl Display for Trigger {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let msg = match self {
+            Trigger::Tap => "Tap",
+            Trigger::DoubleTap => "Double-tap",
+            Trigger::Hold => "Hold",
+        };
+        write!(f, "{msg}")
+    }
+}
@@ -0,0 +1,254 @@
+use std::fmt::{Display, Formatter};
+
+use crate::models::matcher::CompiledRegex;
+
+/// Platform-agnostic description of a window that could be focused.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WindowInfo<H> {
+    pub handle: H,
+    pub title: String,
+    pub minimized: bool,
+    pub on_cursor_monitor: bool,
+}
+
+/// Decides which window to focus when an app has several.
+/// On macOS it only applies to window members, apps come to front as a whole.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum WindowPolicy {
+    #[default]
+    MostRecent,
+    NonMinimized,
+    CursorMonitor,
+    TitleMatch(String),
+}
+
+impl WindowPolicy {
+    /// Expects `windows` to be ordered from most to least recently focused.
+    /// Falls back to the most recent window if none is preferred.
+    pub fn select<'a, H>(&self, windows: &'a [WindowInfo<H>]) -> Option<&'a WindowInfo<H>> {
+        windows
+            .iter()
+            .find(|w| self.prefers(w))
+            .or_else(|| windows.first())
+    }
+
+    fn prefers<H>(&self, window: &WindowInfo<H>) -> bool {
+        match self {
+            WindowPolicy::MostRecent => true,
+            WindowPolicy::NonMinimized => !window.minimized,
+            WindowPolicy::CursorMonitor => window.on_cursor_monitor,
+            WindowPolicy::TitleMatch(pattern) => window
+                .title
+                .to_lowercase()
+                .contains(&pattern.to_lowercase()),
+        }
+    }
+
+    pub fn all() -> [Self; 4] {
+        [
+            WindowPolicy::MostRecent,
+            WindowPolicy::NonMinimized,
+            WindowPolicy::CursorMonitor,
+            WindowPolicy::TitleMatch(String::new()),
+        ]
+    }
+
+    pub fn kind(&self) -> &'static str {
+        match self {
+            WindowPolicy::MostRecent => "recent",
+            WindowPolicy::NonMinimized => "visible",
+            WindowPolicy::CursorMonitor => "monitor",
+            WindowPolicy::TitleMatch(_) => "title",
+        }
+    }
+
+    pub fn from_kind(kind: &str) -> Option<Self> {
+        match kind {
+            "recent" => Some(WindowPolicy::MostRecent),
+            "visible" => Some(WindowPolicy::NonMinimized),
+            "monitor" => Some(WindowPolicy::CursorMonitor),
+            "title" => Some(WindowPolicy::TitleMatch(String::new())),
+            _ => None,
+        }
+    }
+}
+
+impl Display for WindowPolicy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let msg = match self {
+            WindowPolicy::MostRecent => "Most recent window",
+            WindowPolicy::NonMinimized => "Non-minimized window",
+            WindowPolicy::CursorMonitor => "Window on cursor monitor",
+            WindowPolicy::TitleMatch(_) => "Window with matching title",
+        };
+        write!(f, "{msg}")
+    }
+}
+
+/// Matches window titles, used to make single windows group members
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TitlePattern {
+    Substring(String),
+    Regex(CompiledRegex),
+}
+
+impl TitlePattern {
+    pub fn regex(re: impl Into<String>) -> Self {
+        let re = re.into();
+        let pattern = re.clone();
+        TitlePattern::Regex(CompiledRegex::new(re, &pattern, false))
+    }
+
+    pub fn matches(&self, title: &str) -> bool {
+        match self {
+            TitlePattern::Substring(s) => title.to_lowercase().contains(&s.to_lowercase()),
+            TitlePattern::Regex(re) => re.is_match(title),
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        match self {
+            TitlePattern::Substring(s) => !s.is_empty(),
+            TitlePattern::Regex(re) => re.is_valid(),
+        }
+    }
+}
+
+impl Display for TitlePattern {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TitlePattern::Substring(s) => write!(f, "*{s}*"),
+            TitlePattern::Regex(re) => write!(f, "/{}/", re.source()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn window(
+        handle: u32,
+        title: &str,
+        minimized: bool,
+        on_cursor_monitor: bool,
+    ) -> WindowInfo<u32> {
+        WindowInfo {
+            handle,
+            title: title.to_string(),
+            minimized,
+            on_cursor_monitor,
+        }
+    }
+
+    #[test]
+    fn select_empty() {
+        // Arrange
+        let windows: Vec<WindowInfo<u32>> = vec![];
+
+        // Act
+        let result = WindowPolicy::NonMinimized.select(&windows);
+
+        // Assert
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn select_most_recent() {
+        // Arrange
+        let windows = vec![window(1, "A", true, false), window(2, "B", false, true)];
+
+        // Act
+        let result = WindowPolicy::MostRecent.select(&windows);
+
+        // Assert
+        assert_eq!(result.map(|w| w.handle), Some(1));
+    }
+
+    #[test]
+    fn select_non_minimized() {
+        // Arrange
+        let windows = vec![
+            window(1, "A", true, false),
+            window(2, "B", false, false),
+            window(3, "C", false, false),
+        ];
+
+        // Act
+        let result = WindowPolicy::NonMinimized.select(&windows);
+
+        // Assert
+        assert_eq!(result.map(|w| w.handle), Some(2));
+    }
+
+    #[test]
+    fn select_cursor_monitor() {
+        // Arrange
+        let windows = vec![window(1, "A", false, false), window(2, "B", false, true)];
+
+        // Act
+        let result = WindowPolicy::CursorMonitor.select(&windows);
+
+        // Assert
+        assert_eq!(result.map(|w| w.handle), Some(2));
+    }
+
+    #[test]
+    fn select_title_match_ignores_case() {
+        // Arrange
+        let windows = vec![
+            window(1, "Inbox", false, false),
+            window(2, "Grafana - Chrome", false, false),
+        ];
+        let policy = WindowPolicy::TitleMatch("grafana".to_string());
+
+        // Act
+        let result = policy.select(&windows);
+
+        // Assert
+        assert_eq!(result.map(|w| w.handle), Some(2));
+    }
+
+    #[test]
+    fn select_falls_back_to_most_recent() {
+        // Arrange
+        let windows = vec![window(1, "A", true, false), window(2, "B", true, false)];
+
+        // Act
+        let result = WindowPolicy::NonMinimized.select(&windows);
+
+        // Assert
+        assert_eq!(result.map(|w| w.handle), Some(1));
+    }
+
+    #[test]
+    fn title_substring_ignores_case() {
+        // Arrange
+        let pattern = TitlePattern::Substring("grafana".to_string());
+
+        // Act & Assert
+        assert!(pattern.matches("Grafana - Google Chrome"));
+        assert!(!pattern.matches("Inbox - Google Chrome"));
+    }
+
+    #[test]
+    fn title_regex() {
+        // Arrange
+        let pattern = TitlePattern::regex(r"^api( \(\d+\))? - Visual Studio Code$");
+
+        // Act & Assert
+        assert!(pattern.matches("api - Visual Studio Code"));
+        assert!(pattern.matches("api (2) - Visual Studio Code"));
+        assert!(!pattern.matches("web - Visual Studio Code"));
+    }
+
+    #[test]
+    fn title_invalid_regex_never_matches() {
+        // Arrange
+        let pattern = TitlePattern::regex("(unclosed");
+
+        // Act & Assert
+        assert!(!pattern.matches("(unclosed"));
+        assert!(!pattern.is_valid());
+    }
+}
@@ -1,4 +1,6 @@
+mod error;
 mod traits;
+pub use error::{FocusError, OpenError, SelectError};
 pub use traits::*;
 
 #[cfg(target_os = "macos")]
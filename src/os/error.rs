@@ -0,0 +1,185 @@
+use std::fmt::{Display, Formatter};
+use std::io;
+use std::path::PathBuf;
+
+use crate::models::TitlePattern;
+
+/// Why an app or one of its windows could not be brought to front
+#[derive(Debug)]
+pub enum OpenError {
+    NotInstalled {
+        app: String,
+    },
+    NoMatchingWindow {
+        app: String,
+        pattern: TitlePattern,
+    },
+    PermissionDenied {
+        app: String,
+    },
+    /// A window was found, but could not be brought to front
+    Focus {
+        app: String,
+        error: FocusError,
+    },
+    NoSuchPath(PathBuf),
+    #[cfg(target_os = "macos")]
+    Unsupported(&'static str),
+    /// A syscall or launch failed for another reason
+    Failed(anyhow::Error),
+}
+
+impl OpenError {
+    /// Maps the error of spawning the app's executable
+    pub fn launch(app: &str, error: io::Error) -> Self {
+        let app = app.to_string();
+        match error.kind() {
+            io::ErrorKind::NotFound => OpenError::NotInstalled { app },
+            io::ErrorKind::PermissionDenied => OpenError::PermissionDenied { app },
+            _ => OpenError::Failed(
+                anyhow::Error::new(error).context(format!("Could not launch '{app}'")),
+            ),
+        }
+    }
+
+    /// Whether there was nothing to open, as opposed to the OS refusing
+    pub fn is_missing(&self) -> bool {
+        matches!(
+            self,
+            OpenError::NotInstalled { .. }
+                | OpenError::NoMatchingWindow { .. }
+                | OpenError::NoSuchPath(_)
+        )
+    }
+}
+
+impl Display for OpenError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OpenError::NotInstalled { app } => write!(f, "'{app}' is not installed"),
+            OpenError::NoMatchingWindow { app, pattern } => {
+                write!(f, "No window of '{app}' matches {pattern}")
+            }
+            OpenError::PermissionDenied { app } => {
+                write!(f, "Not allowed to open '{app}', check its permissions")
+            }
+            OpenError::Focus { app, error } => {
+                write!(f, "Could not bring '{app}' to front: {error}")
+            }
+            OpenError::NoSuchPath(path) => write!(f, "'{}' does not exist", path.display()),
+            #[cfg(target_os = "macos")]
+            OpenError::Unsupported(what) => {
+                write!(f, "{what} is not supported on this platform yet")
+            }
+            OpenError::Failed(e) => write!(f, "{e:#}"),
+        }
+    }
+}
+
+impl std::error::Error for OpenError {}
+
+impl From<anyhow::Error> for OpenError {
+    fn from(error: anyhow::Error) -> Self {
+        OpenError::Failed(error)
+    }
+}
+
+/// Why the OS refused to bring a window to front
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FocusError {
+    /// The window is minimized and could not be restored
+    Restore,
+    /// Only the app in front may hand on the focus, which the OS enforces now and then
+    Foreground,
+    /// Single windows can only be raised with accessibility access
+    #[cfg(target_os = "macos")]
+    Accessibility,
+}
+
+impl Display for FocusError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FocusError::Restore => write!(f, "the minimized window could not be restored"),
+            FocusError::Foreground => write!(f, "the OS did not let it take the focus"),
+            #[cfg(target_os = "macos")]
+            FocusError::Accessibility => {
+                write!(
+                    f,
+                    "GroupCtrl needs accessibility access in the system settings"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for FocusError {}
+
+/// Why picking an app in the file dialog did not give an app
+#[derive(Debug)]
+pub enum SelectError {
+    /// The picked file is not an app we can open
+    #[cfg(target_os = "macos")]
+    NotAnApp(PathBuf),
+    Failed(anyhow::Error),
+}
+
+impl Display for SelectError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            #[cfg(target_os = "macos")]
+            SelectError::NotAnApp(path) => write!(f, "'{}' is not an app", path.display()),
+            SelectError::Failed(e) => write!(f, "{e:#}"),
+        }
+    }
+}
+
+impl std::error::Error for SelectError {}
+
+impl From<anyhow::Error> for SelectError {
+    fn from(error: anyhow::Error) -> Self {
+        SelectError::Failed(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn launch_errors_by_kind() {
+        // Arrange
+        let not_found = io::Error::from(io::ErrorKind::NotFound);
+        let denied = io::Error::from(io::ErrorKind::PermissionDenied);
+        let other = io::Error::other("boom");
+
+        // Act
+        let not_found = OpenError::launch("app", not_found);
+        let denied = OpenError::launch("app", denied);
+        let other = OpenError::launch("app", other);
+
+        // Assert
+        assert!(matches!(not_found, OpenError::NotInstalled { .. }));
+        assert!(not_found.is_missing());
+        assert!(matches!(denied, OpenError::PermissionDenied { .. }));
+        assert!(!denied.is_missing());
+        assert!(other.to_string().starts_with("Could not launch 'app'"));
+    }
+
+    #[test]
+    fn focus_error_is_not_missing() {
+        // Arrange
+        let app = "app".to_string();
+
+        // Act
+        let refused = OpenError::Focus {
+            app,
+            error: FocusError::Foreground,
+        };
+        let no_path = OpenError::NoSuchPath(PathBuf::from("/nowhere"));
+
+        // Assert
+        assert!(!refused.is_missing());
+        assert!(no_path.is_missing());
+        assert_eq!(no_path.to_string(), "'/nowhere' does not exist");
+    }
+}
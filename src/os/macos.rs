@@ -1,8 +1,16 @@
+mod accessibility;
 mod app;
 mod app_dialog;
+mod bundle;
+mod catalog;
 mod keyboard;
 mod open;
+mod shell;
+mod window_model;
 
 pub use app::App;
 pub use app_dialog::AppDialog;
+pub use catalog::Catalog;
 pub use keyboard::Keyboard;
+pub use shell::Shell;
+pub use window_model::WindowManager;
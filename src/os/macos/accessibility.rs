@@ -0,0 +1,183 @@
+// The few accessibility calls needed to read window titles and raise single windows
+
+use std::ffi::{c_char, c_void};
+use std::ptr;
+
+use anyhow::bail;
+
+type CFTypeRef = *const c_void;
+
+const UTF8: u32 = 0x0800_0100; // kCFStringEncodingUTF8
+const AX_SUCCESS: i32 = 0;
+
+#[link(name = "CoreFoundation", kind = "framework")]
+unsafe extern "C" {
+    static kCFBooleanTrue: CFTypeRef;
+    static kCFBooleanFalse: CFTypeRef;
+    fn CFRetain(cf: CFTypeRef) -> CFTypeRef;
+    fn CFRelease(cf: CFTypeRef);
+    fn CFGetTypeID(cf: CFTypeRef) -> usize;
+    fn CFStringGetTypeID() -> usize;
+    fn CFBooleanGetTypeID() -> usize;
+    fn CFArrayGetTypeID() -> usize;
+    fn CFBooleanGetValue(boolean: CFTypeRef) -> u8;
+    fn CFArrayGetCount(array: CFTypeRef) -> isize;
+    fn CFArrayGetValueAtIndex(array: CFTypeRef, index: isize) -> CFTypeRef;
+    fn CFStringCreateWithBytes(
+        allocator: CFTypeRef,
+        bytes: *const u8,
+        length: isize,
+        encoding: u32,
+        is_external: u8,
+    ) -> CFTypeRef;
+    fn CFStringGetLength(string: CFTypeRef) -> isize;
+    fn CFStringGetMaximumSizeForEncoding(length: isize, encoding: u32) -> isize;
+    fn CFStringGetCString(string: CFTypeRef, buffer: *mut c_char, size: isize, encoding: u32)
+    -> u8;
+}
+
+#[link(name = "ApplicationServices", kind = "framework")]
+unsafe extern "C" {
+    fn AXIsProcessTrusted() -> u8;
+    fn AXUIElementCreateApplication(pid: i32) -> CFTypeRef;
+    fn AXUIElementCopyAttributeValue(
+        element: CFTypeRef,
+        attribute: CFTypeRef,
+        value: *mut CFTypeRef,
+    ) -> i32;
+    fn AXUIElementSetAttributeValue(
+        element: CFTypeRef,
+        attribute: CFTypeRef,
+        value: CFTypeRef,
+    ) -> i32;
+    fn AXUIElementPerformAction(element: CFTypeRef, action: CFTypeRef) -> i32;
+}
+
+/// Whether the user granted GroupCtrl accessibility access, asking doesn't prompt them
+pub fn is_trusted() -> bool {
+    unsafe { AXIsProcessTrusted() != 0 }
+}
+
+/// An owned Core Foundation object, released when dropped
+struct Owned(CFTypeRef);
+
+impl Owned {
+    /// Takes ownership of a +1 reference, None for null
+    fn new(cf: CFTypeRef) -> Option<Self> {
+        // a null Owned must never exist, it would be released on drop
+        if cf.is_null() { None } else { Some(Owned(cf)) }
+    }
+
+    fn string(s: &str) -> Self {
+        let cf =
+            unsafe { CFStringCreateWithBytes(ptr::null(), s.as_ptr(), s.len() as isize, UTF8, 0) };
+        Owned(cf)
+    }
+
+    fn has_type(&self, type_id: usize) -> bool {
+        unsafe { CFGetTypeID(self.0) == type_id }
+    }
+
+    fn to_string(&self) -> Option<String> {
+        if !self.has_type(unsafe { CFStringGetTypeID() }) {
+            return None;
+        }
+        unsafe {
+            let size = CFStringGetMaximumSizeForEncoding(CFStringGetLength(self.0), UTF8) + 1;
+            let mut buffer = vec![0u8; size as usize];
+            if CFStringGetCString(self.0, buffer.as_mut_ptr().cast(), size, UTF8) == 0 {
+                return None;
+            }
+            let end = buffer.iter().position(|&b| b == 0).unwrap_or(buffer.len());
+            buffer.truncate(end);
+            String::from_utf8(buffer).ok()
+        }
+    }
+
+    fn to_bool(&self) -> Option<bool> {
+        if !self.has_type(unsafe { CFBooleanGetTypeID() }) {
+            return None;
+        }
+        Some(unsafe { CFBooleanGetValue(self.0) != 0 })
+    }
+
+    /// The elements of an array, each retained on its own
+    fn to_vec(&self) -> Vec<Owned> {
+        if !self.has_type(unsafe { CFArrayGetTypeID() }) {
+            return Vec::new();
+        }
+        unsafe {
+            (0..CFArrayGetCount(self.0))
+                .map(|i| Owned(CFRetain(CFArrayGetValueAtIndex(self.0, i))))
+                .collect()
+        }
+    }
+}
+
+impl Drop for Owned {
+    fn drop(&mut self) {
+        unsafe { CFRelease(self.0) }
+    }
+}
+
+/// An app or one of its windows, as seen by the accessibility API
+pub struct Element(Owned);
+
+impl Element {
+    pub fn app(pid: i32) -> Self {
+        Element(Owned(unsafe { AXUIElementCreateApplication(pid) }))
+    }
+
+    /// The app's windows, front to back
+    pub fn windows(&self) -> Vec<Element> {
+        self.attribute("AXWindows")
+            .map(|windows| windows.to_vec().into_iter().map(Element).collect())
+            .unwrap_or_default()
+    }
+
+    pub fn focused_window(&self) -> Option<Element> {
+        self.attribute("AXFocusedWindow").map(Element)
+    }
+
+    pub fn title(&self) -> Option<String> {
+        self.attribute("AXTitle")?.to_string()
+    }
+
+    pub fn is_minimized(&self) -> bool {
+        self.attribute("AXMinimized")
+            .and_then(|minimized| minimized.to_bool())
+            .unwrap_or(false)
+    }
+
+    /// Brings the window to front, restoring it first if it is minimized
+    pub fn raise(&self, app: &Element) -> anyhow::Result<()> {
+        if self.is_minimized() {
+            self.set_flag("AXMinimized", false)?;
+        }
+        let action = Owned::string("AXRaise");
+        if unsafe { AXUIElementPerformAction(self.0.0, action.0) } != AX_SUCCESS {
+            bail!("accessibility action 'AXRaise' failed");
+        }
+        self.set_flag("AXMain", true)?;
+        app.set_flag("AXFrontmost", true)
+    }
+
+    fn attribute(&self, name: &str) -> Option<Owned> {
+        let name = Owned::string(name);
+        let mut value: CFTypeRef = ptr::null();
+        let error = unsafe { AXUIElementCopyAttributeValue(self.0.0, name.0, &mut value) };
+        if error != AX_SUCCESS {
+            return None;
+        }
+        Owned::new(value)
+    }
+
+    fn set_flag(&self, name: &str, on: bool) -> anyhow::Result<()> {
+        let attribute = Owned::string(name);
+        let value = unsafe { if on { kCFBooleanTrue } else { kCFBooleanFalse } };
+        if unsafe { AXUIElementSetAttributeValue(self.0.0, attribute.0, value) } != AX_SUCCESS {
+            bail!("could not set accessibility attribute '{name}'");
+        }
+        Ok(())
+    }
+}
@@ -14,6 +14,12 @@ impl Identifiable<String> for App {
     }
 }
 
+impl From<String> for App {
+    fn from(bundle_id: String) -> Self {
+        Self { bundle_id }
+    }
+}
+
 impl App {
     pub fn display(&self) -> String {
         let name = self.bundle_id.split(".").last().unwrap_or(&self.bundle_id);
@@ -1,14 +1,10 @@
-use std::fs::File;
-use std::path::Path;
-
-use anyhow::Context;
-
-use crate::os::{App, AppSelection};
+use super::bundle::info_plist_value;
+use crate::os::{App, AppSelection, SelectError};
 
 pub struct AppDialog;
 
 impl AppSelection for AppDialog {
-    async fn select_app() -> anyhow::Result<Option<App>> {
+    async fn select_app() -> Result<Option<App>, SelectError> {
         let Some(app_path) = rfd::AsyncFileDialog::new()
             .add_filter("Applications", &["app"])
             .set_directory("/Applications")
@@ -17,19 +13,10 @@ impl AppSelection for AppDialog {
         else {
             return Ok(None);
         };
-        let bundle_id = get_bundle_id(app_path.path())?;
-        Ok(Some(App { bundle_id }))
+        // reading a bundle may fail for other reasons, which are worth telling apart
+        match info_plist_value(app_path.path(), "CFBundleIdentifier")? {
+            Some(bundle_id) => Ok(Some(App { bundle_id })),
+            None => Err(SelectError::NotAnApp(app_path.path().to_path_buf())),
+        }
     }
 }
-
-fn get_bundle_id(app_path: &Path) -> anyhow::Result<String> {
-    let plist_path = app_path.join("Contents/Info.plist");
-    let file = File::open(&plist_path)?;
-    let plist: plist::Value = plist::from_reader(file)?;
-    plist
-        .as_dictionary()
-        .and_then(|dict| dict.get("CFBundleIdentifier"))
-        .and_then(|value| value.as_string())
-        .map(|s| s.to_string())
-        .context("bundle identifier not found")
-}
@@ -0,0 +1,26 @@
+use std::fs::File;
+use std::io;
+use std::path::Path;
+
+use anyhow::Context;
+
+/// The value of the bundle's Info.plist, or None if the path is no bundle or lacks the key
+pub fn info_plist_value(app_path: &Path, key: &str) -> anyhow::Result<Option<String>> {
+    let plist_path = app_path.join("Contents/Info.plist");
+    let file = match File::open(&plist_path) {
+        Ok(file) => file,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => {
+            return Err(
+                anyhow::Error::new(e).context(format!("Could not read '{}'", plist_path.display()))
+            );
+        }
+    };
+    let plist: plist::Value = plist::from_reader(file)
+        .with_context(|| format!("Could not parse '{}'", plist_path.display()))?;
+    Ok(plist
+        .as_dictionary()
+        .and_then(|dict| dict.get(key))
+        .and_then(|value| value.as_string())
+        .map(|s| s.to_string()))
+}
@@ -0,0 +1,36 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::bundle::info_plist_value;
+use crate::os::{App, AppCatalog};
+use crate::util::expand_home;
+
+const APP_DIRS: [&str; 3] = ["/Applications", "/System/Applications", "~/Applications"];
+
+pub struct Catalog;
+
+impl AppCatalog for Catalog {
+    fn installed_apps() -> anyhow::Result<Vec<(App, PathBuf)>> {
+        let mut apps = Vec::new();
+        for dir in APP_DIRS {
+            collect_bundles(Path::new(&expand_home(dir)), 2, &mut apps);
+        }
+        Ok(apps)
+    }
+}
+
+// bundles can sit in subfolders like /Applications/Utilities, but never inside other bundles
+fn collect_bundles(dir: &Path, depth: usize, apps: &mut Vec<(App, PathBuf)>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for path in entries.flatten().map(|e| e.path()) {
+        if path.extension().is_some_and(|ext| ext == "app") {
+            if let Ok(Some(bundle_id)) = info_plist_value(&path, "CFBundleIdentifier") {
+                apps.push((App { bundle_id }, path));
+            }
+        } else if depth > 1 && path.is_dir() {
+            collect_bundles(&path, depth - 1, apps);
+        }
+    }
+}
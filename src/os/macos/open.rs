@@ -1,25 +1,123 @@
-use anyhow::bail;
+use anyhow::anyhow;
 use log::info;
-use objc2_app_kit::NSWorkspace;
-use objc2_foundation::NSString;
+use objc2_app_kit::{NSRunningApplication, NSWorkspace, NSWorkspaceOpenConfiguration};
+use objc2_foundation::{NSArray, NSDictionary, NSString, NSURL};
 
+use super::accessibility::{self, Element};
 use super::app::App;
-use crate::os::Openable;
+use crate::models::{LaunchOptions, TitlePattern, WindowInfo, WindowPolicy};
+use crate::os::{FocusError, OpenError, Openable};
+use crate::util::expand_home;
 
 impl Openable for App {
-    fn open(&self) -> anyhow::Result<()> {
+    // Window choice is left to the OS, openURL brings forward the app's key window
+    fn open(
+        &self,
+        _window_policy: &WindowPolicy,
+        launch_options: &LaunchOptions,
+    ) -> Result<(), OpenError> {
         info!("Opening app {self}");
         let workspace = NSWorkspace::sharedWorkspace();
         let bundle_id = NSString::from_str(&self.bundle_id);
         let Some(app_url) = workspace.URLForApplicationWithBundleIdentifier(&bundle_id) else {
-            bail!("Could not find app with bundle id '{bundle_id}'");
+            return Err(OpenError::NotInstalled {
+                app: bundle_id.to_string(),
+            });
         };
-        // TODO use openApplicationAtUrl (requires async)
+        if !launch_options.is_empty() && !is_running(&bundle_id) {
+            // LaunchServices starts apps from / and has no way to change that
+            if launch_options.working_dir.is_some() {
+                return Err(OpenError::Unsupported("Launching with a working directory"));
+            }
+            launch(&workspace, &app_url, launch_options);
+            return Ok(());
+        }
+        // unlike openApplicationAtURL, openURL reports a failure right away
         if !workspace.openURL(&app_url) {
-            bail!("syscall 'openURL' failed");
+            return Err(anyhow!("syscall 'openURL' failed").into());
         }
         Ok(())
     }
+
+    // Single windows are only reachable through the accessibility API, never launches
+    fn open_window(
+        &self,
+        window_policy: &WindowPolicy,
+        title_pattern: &TitlePattern,
+    ) -> Result<(), OpenError> {
+        info!("Opening window {title_pattern} of app {self}");
+        if !accessibility::is_trusted() {
+            return Err(OpenError::Focus {
+                app: self.bundle_id.clone(),
+                error: FocusError::Accessibility,
+            });
+        }
+        let bundle_id = NSString::from_str(&self.bundle_id);
+        let running = NSRunningApplication::runningApplicationsWithBundleIdentifier(&bundle_id);
+        for app in running.iter() {
+            let app = Element::app(app.processIdentifier());
+            let windows: Vec<(Element, String)> = app
+                .windows()
+                .into_iter()
+                .filter_map(|w| w.title().map(|title| (w, title)))
+                .filter(|(_, title)| title_pattern.matches(title))
+                .collect();
+            let candidates: Vec<WindowInfo<usize>> = windows
+                .iter()
+                .enumerate()
+                .map(|(i, (window, title))| WindowInfo {
+                    handle: i,
+                    title: title.clone(),
+                    minimized: window.is_minimized(),
+                    on_cursor_monitor: false, // window frames aren't read, so this falls back
+                })
+                .collect();
+            if let Some(chosen) = window_policy.select(&candidates) {
+                windows[chosen.handle].0.raise(&app)?;
+                return Ok(());
+            }
+        }
+        Err(OpenError::NoMatchingWindow {
+            app: self.bundle_id.clone(),
+            pattern: title_pattern.clone(),
+        })
+    }
+}
+
+fn is_running(bundle_id: &NSString) -> bool {
+    NSRunningApplication::runningApplicationsWithBundleIdentifier(bundle_id).count() > 0
+}
+
+// openURL can't pass arguments, LaunchServices reports the outcome only asynchronously
+fn launch(workspace: &NSWorkspace, app_url: &NSURL, launch_options: &LaunchOptions) {
+    let args: Vec<_> = launch_options
+        .args
+        .iter()
+        .map(|a| NSString::from_str(&expand_home(a)))
+        .collect();
+    let keys: Vec<_> = launch_options
+        .env
+        .iter()
+        .map(|(k, _)| NSString::from_str(k))
+        .collect();
+    let values: Vec<_> = launch_options
+        .env
+        .iter()
+        .map(|(_, v)| NSString::from_str(v))
+        .collect();
+    let keys: Vec<&NSString> = keys.iter().map(|k| &**k).collect();
+    let values: Vec<&NSString> = values.iter().map(|v| &**v).collect();
+    // SAFETY: the configuration only holds strings, and without a handler nothing is called back
+    unsafe {
+        let configuration = NSWorkspaceOpenConfiguration::configuration();
+        configuration.setArguments(&NSArray::from_retained_slice(&args));
+        configuration.setEnvironment(&NSDictionary::from_slices(&keys, &values));
+        workspace.openApplicationAtURL_configuration_completionHandler(
+            app_url,
+            &configuration,
+            None,
+        );
+    }
 }
 
 #[cfg(test)]
@@ -45,8 +143,13 @@ mod tests {
             bundle_id: "com.apple.finder".to_string(),
         };
         // This only means the command was received, but should be fine
-        assert!(app.open().is_ok());
-        initial_app.open().unwrap(); // restore focus
+        assert!(
+            app.open(&WindowPolicy::default(), &LaunchOptions::default())
+                .is_ok()
+        );
+        initial_app
+            .open(&WindowPolicy::default(), &LaunchOptions::default())
+            .unwrap(); // restore focus
     }
 
     #[test]
@@ -54,6 +157,9 @@ mod tests {
         let fake_app = App {
             bundle_id: "com.test.fake".to_string(),
         };
-        assert!(fake_app.open().is_err());
+        assert!(matches!(
+            fake_app.open(&WindowPolicy::default(), &LaunchOptions::default()),
+            Err(OpenError::NotInstalled { .. })
+        ));
     }
 }
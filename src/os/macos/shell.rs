@@ -0,0 +1,47 @@
+use std::path::Path;
+use std::process::Command;
+
+use anyhow::{Context, anyhow, bail};
+use log::info;
+use objc2_app_kit::NSWorkspace;
+use objc2_foundation::{NSString, NSURL};
+
+use crate::os::{OpenError, ShellBehavior};
+use crate::util::reap;
+
+pub struct Shell;
+
+impl ShellBehavior for Shell {
+    fn open_url(url: &str) -> anyhow::Result<()> {
+        info!("Opening url {url}");
+        let Some(ns_url) = NSURL::URLWithString(&NSString::from_str(url)) else {
+            bail!("Invalid url '{url}'");
+        };
+        if !NSWorkspace::sharedWorkspace().openURL(&ns_url) {
+            bail!("syscall 'openURL' failed");
+        }
+        Ok(())
+    }
+
+    fn open_path(path: &Path) -> Result<(), OpenError> {
+        info!("Opening path {}", path.display());
+        if !path.exists() {
+            return Err(OpenError::NoSuchPath(path.to_path_buf()));
+        }
+        let ns_url = NSURL::fileURLWithPath(&NSString::from_str(&path.to_string_lossy()));
+        if !NSWorkspace::sharedWorkspace().openURL(&ns_url) {
+            return Err(anyhow!("syscall 'openURL' failed").into());
+        }
+        Ok(())
+    }
+
+    fn run_command(command: &str) -> anyhow::Result<()> {
+        info!("Running command {command}");
+        let child = Command::new("sh")
+            .args(["-c", command])
+            .spawn()
+            .with_context(|| format!("Could not run '{command}'"))?;
+        reap(child, command.to_string());
+        Ok(())
+    }
+}
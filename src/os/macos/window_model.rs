@@ -0,0 +1,81 @@
+use anyhow::{Context, bail};
+use objc2_app_kit::{NSApplicationActivationPolicy, NSRunningApplication, NSWorkspace};
+
+use super::accessibility::{self, Element};
+use crate::os::WindowModel;
+
+#[derive(Default)]
+pub struct WindowManager;
+
+fn with_running_app(
+    pid: i32,
+    f: impl FnOnce(&NSRunningApplication) -> bool,
+) -> anyhow::Result<bool> {
+    let app = NSRunningApplication::runningApplicationWithProcessIdentifier(pid)
+        .with_context(|| format!("No running app with pid {pid}"))?;
+    Ok(f(&app))
+}
+
+impl WindowModel for WindowManager {
+    // Windows can't be hidden individually without accessibility access, so we hide whole apps
+    type Window = i32; // process id
+
+    // our own window is left out, hiding the others must not hide the settings
+    fn visible_windows(&self) -> anyhow::Result<Vec<(String, i32)>> {
+        let own_pid = NSRunningApplication::currentApplication().processIdentifier();
+        let apps = NSWorkspace::sharedWorkspace().runningApplications();
+        Ok(apps
+            .iter()
+            .filter(|app| app.processIdentifier() != own_pid)
+            .filter(|app| {
+                app.activationPolicy() == NSApplicationActivationPolicy::Regular && !app.isHidden()
+            })
+            .filter_map(|app| Some((app.bundleIdentifier()?.to_string(), app.processIdentifier())))
+            .collect())
+    }
+
+    fn frontmost_app(&self) -> Option<String> {
+        let app = NSWorkspace::sharedWorkspace().frontmostApplication()?;
+        Some(app.bundleIdentifier()?.to_string())
+    }
+
+    // read through the accessibility API, without access there is no title
+    fn frontmost_title(&self) -> Option<String> {
+        if !accessibility::is_trusted() {
+            return None;
+        }
+        let app = NSWorkspace::sharedWorkspace().frontmostApplication()?;
+        Element::app(app.processIdentifier())
+            .focused_window()?
+            .title()
+    }
+
+    // unbundled builds have no id, but then they are never reported as frontmost either
+    fn own_app(&self) -> Option<String> {
+        let app = NSRunningApplication::currentApplication();
+        Some(app.bundleIdentifier()?.to_string())
+    }
+
+    fn running_apps(&self) -> anyhow::Result<Vec<String>> {
+        let apps = NSWorkspace::sharedWorkspace().runningApplications();
+        Ok(apps
+            .iter()
+            .filter(|app| app.activationPolicy() == NSApplicationActivationPolicy::Regular)
+            .filter_map(|app| Some(app.bundleIdentifier()?.to_string()))
+            .collect())
+    }
+
+    fn hide(&mut self, pid: &i32) -> anyhow::Result<()> {
+        if !with_running_app(*pid, |app| app.hide())? {
+            bail!("syscall 'hide' failed");
+        }
+        Ok(())
+    }
+
+    fn restore(&mut self, pid: &i32) -> anyhow::Result<()> {
+        if !with_running_app(*pid, |app| app.unhide())? {
+            bail!("syscall 'unhide' failed");
+        }
+        Ok(())
+    }
+}
@@ -1,6 +1,10 @@
+use std::fmt::Debug;
+use std::path::{Path, PathBuf};
+
 use global_hotkey::hotkey::Modifiers;
 
-use crate::os::App;
+use crate::models::{LaunchOptions, TitlePattern, WindowPolicy};
+use crate::os::{App, OpenError, SelectError};
 
 pub trait KeyboardBehavior {
     fn modifier_format() -> [(Modifiers, &'static str); 4];
@@ -8,9 +12,46 @@ pub trait KeyboardBehavior {
 }
 
 pub trait AppSelection {
-    async fn select_app() -> anyhow::Result<Option<App>>;
+    async fn select_app() -> Result<Option<App>, SelectError>;
+}
+
+pub trait AppCatalog {
+    /// Apps found in the usual install locations, along with where they are installed
+    fn installed_apps() -> anyhow::Result<Vec<(App, PathBuf)>>;
 }
 
 pub trait Openable {
-    fn open(&self) -> anyhow::Result<()>;
+    /// Focuses a running instance, or launches the app with `launch_options`
+    fn open(
+        &self,
+        window_policy: &WindowPolicy,
+        launch_options: &LaunchOptions,
+    ) -> Result<(), OpenError>;
+
+    /// Focuses a running window whose title matches, never launches
+    fn open_window(
+        &self,
+        window_policy: &WindowPolicy,
+        title_pattern: &TitlePattern,
+    ) -> Result<(), OpenError>;
+}
+
+pub trait ShellBehavior {
+    fn open_url(url: &str) -> anyhow::Result<()>;
+    fn open_path(path: &Path) -> Result<(), OpenError>;
+    fn run_command(command: &str) -> anyhow::Result<()>;
+}
+
+pub trait WindowModel {
+    type Window: Clone + PartialEq + Debug;
+
+    /// Visible windows along with the id of the app they belong to
+    fn visible_windows(&self) -> anyhow::Result<Vec<(String, Self::Window)>>;
+    fn frontmost_app(&self) -> Option<String>;
+    fn frontmost_title(&self) -> Option<String>;
+    /// GroupCtrl's own id, as the other methods would report it
+    fn own_app(&self) -> Option<String>;
+    fn running_apps(&self) -> anyhow::Result<Vec<String>>;
+    fn hide(&mut self, window: &Self::Window) -> anyhow::Result<()>;
+    fn restore(&mut self, window: &Self::Window) -> anyhow::Result<()>;
 }
@@ -1,8 +1,15 @@
 mod app;
 mod app_dialog;
+mod catalog;
 mod keyboard;
 mod open;
+mod shell;
+mod win32;
+mod window_model;
 
 pub use app::App;
 pub use app_dialog::AppDialog;
+pub use catalog::Catalog;
 pub use keyboard::Keyboard;
+pub use shell::Shell;
+pub use window_model::WindowManager;
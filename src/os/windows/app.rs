@@ -14,6 +14,12 @@ impl Identifiable<String> for App {
     }
 }
 
+impl From<String> for App {
+    fn from(exe_path: String) -> Self {
+        Self { exe_path }
+    }
+}
+
 impl App {
     fn display(&self) -> String {
         let exe_name = self.exe_path.split("\\").last().unwrap_or(&self.exe_path);
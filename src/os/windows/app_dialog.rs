@@ -1,9 +1,9 @@
-use crate::os::{App, AppSelection};
+use crate::os::{App, AppSelection, SelectError};
 
 pub struct AppDialog;
 
 impl AppSelection for AppDialog {
-    async fn select_app() -> anyhow::Result<Option<App>> {
+    async fn select_app() -> Result<Option<App>, SelectError> {
         todo!();
     }
 }
@@ -0,0 +1,39 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::os::{App, AppCatalog};
+
+const APP_DIR_VARS: [&str; 3] = ["ProgramFiles", "ProgramFiles(x86)", "LOCALAPPDATA"];
+
+pub struct Catalog;
+
+impl AppCatalog for Catalog {
+    fn installed_apps() -> anyhow::Result<Vec<(App, PathBuf)>> {
+        let mut apps = Vec::new();
+        for var in APP_DIR_VARS {
+            if let Ok(dir) = env::var(var) {
+                // e.g. LOCALAPPDATA\Discord\app-1.0.9\Discord.exe
+                collect_executables(Path::new(&dir), 3, &mut apps);
+            }
+        }
+        Ok(apps)
+    }
+}
+
+fn collect_executables(dir: &Path, depth: usize, apps: &mut Vec<(App, PathBuf)>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for path in entries.flatten().map(|e| e.path()) {
+        if path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("exe"))
+        {
+            let exe_path = path.to_string_lossy().into_owned();
+            apps.push((App { exe_path }, path));
+        } else if depth > 1 && path.is_dir() {
+            collect_executables(&path, depth - 1, apps);
+        }
+    }
+}
@@ -1,10 +1,22 @@
-use crate::os::{App, Openable};
+use crate::models::{LaunchOptions, TitlePattern, WindowPolicy};
+use crate::os::{App, OpenError, Openable};
 
 mod open;
-mod win32;
 
 impl Openable for App {
-    fn open(&self) -> anyhow::Result<()> {
-        open::open(&self.exe_path)
+    fn open(
+        &self,
+        window_policy: &WindowPolicy,
+        launch_options: &LaunchOptions,
+    ) -> Result<(), OpenError> {
+        open::open(&self.exe_path, window_policy, launch_options)
+    }
+
+    fn open_window(
+        &self,
+        window_policy: &WindowPolicy,
+        title_pattern: &TitlePattern,
+    ) -> Result<(), OpenError> {
+        open::open_window(&self.exe_path, window_policy, title_pattern)
     }
 }
@@ -1,10 +1,49 @@
-use anyhow::bail;
+use std::process::Command;
 
-use super::win32::focus;
+use log::info;
 
-pub fn open(exe_path: &str) -> anyhow::Result<()> {
-    if !focus(exe_path)? {
-        bail!("Couldn't find window");
+use crate::models::{LaunchOptions, TitlePattern, WindowPolicy};
+use crate::os::OpenError;
+use crate::os::windows::win32::focus;
+use crate::util::expand_home;
+
+pub fn open(
+    exe_path: &str,
+    window_policy: &WindowPolicy,
+    launch_options: &LaunchOptions,
+) -> Result<(), OpenError> {
+    // running instances are matched by executable, whatever arguments they were started with
+    if !focus(exe_path, window_policy, None)? {
+        info!("No window found, launching '{exe_path}'");
+        command(exe_path, launch_options)
+            .spawn()
+            .map_err(|e| OpenError::launch(exe_path, e))?;
     };
     Ok(())
 }
+
+fn command(exe_path: &str, launch_options: &LaunchOptions) -> Command {
+    let mut command = Command::new(exe_path);
+    command
+        .args(launch_options.args.iter().map(|a| expand_home(a)))
+        .envs(launch_options.env.iter().map(|(k, v)| (k, v)));
+    if let Some(dir) = &launch_options.working_dir {
+        command.current_dir(expand_home(&dir.to_string_lossy()));
+    }
+    command
+}
+
+pub fn open_window(
+    exe_path: &str,
+    window_policy: &WindowPolicy,
+    title_pattern: &TitlePattern,
+) -> Result<(), OpenError> {
+    // launching wouldn't help, there is no telling which title a new window gets
+    if !focus(exe_path, window_policy, Some(title_pattern))? {
+        return Err(OpenError::NoMatchingWindow {
+            app: exe_path.to_string(),
+            pattern: title_pattern.clone(),
+        });
+    }
+    Ok(())
+}
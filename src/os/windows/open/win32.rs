@@ -1,6 +0,0 @@
-mod api;
-mod focus;
-mod pid_to_exe;
-mod windows;
-
-pub(super) use focus::focus;
@@ -0,0 +1,55 @@
+use std::os::windows::process::CommandExt;
+use std::path::Path;
+use std::process::{Child, Command};
+
+use anyhow::Context;
+use log::info;
+
+use crate::os::{OpenError, ShellBehavior};
+use crate::util::reap;
+
+const CREATE_NO_WINDOW: u32 = 0x08000000;
+
+pub struct Shell;
+
+impl ShellBehavior for Shell {
+    fn open_url(url: &str) -> anyhow::Result<()> {
+        info!("Opening url {url}");
+        // explorer hands urls to the default browser
+        let child = Command::new("explorer")
+            .arg(url)
+            .spawn()
+            .with_context(|| format!("Could not open '{url}'"))?;
+        wait_for_explorer(child);
+        Ok(())
+    }
+
+    fn open_path(path: &Path) -> Result<(), OpenError> {
+        info!("Opening path {}", path.display());
+        if !path.exists() {
+            return Err(OpenError::NoSuchPath(path.to_path_buf()));
+        }
+        let child = Command::new("explorer")
+            .arg(path)
+            .spawn()
+            .with_context(|| format!("Could not open '{}'", path.display()))?;
+        wait_for_explorer(child);
+        Ok(())
+    }
+
+    fn run_command(command: &str) -> anyhow::Result<()> {
+        info!("Running command {command}");
+        let child = Command::new("cmd")
+            .args(["/C", command])
+            .creation_flags(CREATE_NO_WINDOW)
+            .spawn()
+            .with_context(|| format!("Could not run '{command}'"))?;
+        reap(child, command.to_string());
+        Ok(())
+    }
+}
+
+/// Like `reap`, but explorer exits with 1 even when it opened what it was given
+fn wait_for_explorer(mut child: Child) {
+    std::thread::spawn(move || child.wait());
+}
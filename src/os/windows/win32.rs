@@ -0,0 +1,12 @@
+mod api;
+mod focus;
+mod pid_to_exe;
+mod show;
+mod windows;
+
+pub(super) use self::windows::{
+    collect_main_windows, current_exe, foreground_window, window_exe, window_title,
+};
+pub(super) use ::windows::Win32::Foundation::HWND;
+pub(super) use focus::focus;
+pub(super) use show::{is_minimized, minimize, restore};
@@ -1,3 +1,4 @@
 pub(super) use windows::Win32::Foundation::*;
+pub(super) use windows::Win32::Graphics::Gdi::*;
 pub(super) use windows::Win32::System::Threading::*;
 pub(super) use windows::Win32::UI::WindowsAndMessaging::*;
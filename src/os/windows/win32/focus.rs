@@ -0,0 +1,40 @@
+use super::api as win32;
+use super::windows::{collect_main_windows, describe_windows, find_matching_windows};
+use crate::models::{TitlePattern, WindowPolicy};
+use crate::os::{FocusError, OpenError};
+
+pub fn focus(
+    exe_path: &str,
+    window_policy: &WindowPolicy,
+    title_pattern: Option<&TitlePattern>,
+) -> Result<bool, OpenError> {
+    let main_windows = collect_main_windows()?;
+    let matching_windows = find_matching_windows(&main_windows, exe_path)?;
+    let mut candidates = describe_windows(&matching_windows)?;
+    if let Some(pattern) = title_pattern {
+        candidates.retain(|w| pattern.matches(&w.title));
+    }
+    let result = window_policy.select(&candidates);
+    if let Some(window) = result {
+        focus_window(window.handle).map_err(|error| OpenError::Focus {
+            app: exe_path.to_string(),
+            error,
+        })?
+    }
+    Ok(result.is_some())
+}
+
+fn focus_window(window: win32::HWND) -> Result<(), FocusError> {
+    unsafe {
+        if win32::IsIconic(window).as_bool() {
+            // undo minimization
+            if !win32::ShowWindow(window, win32::SW_RESTORE).as_bool() {
+                return Err(FocusError::Restore);
+            }
+        }
+        if !win32::SetForegroundWindow(window).as_bool() {
+            return Err(FocusError::Foreground);
+        }
+    }
+    Ok(())
+}
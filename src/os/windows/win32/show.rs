@@ -0,0 +1,18 @@
+use super::api as win32;
+
+pub fn is_minimized(window: win32::HWND) -> bool {
+    unsafe { win32::IsIconic(window).as_bool() }
+}
+
+// ShowWindow returns the previous visibility, so there is no failure to check for
+pub fn minimize(window: win32::HWND) {
+    unsafe {
+        let _ = win32::ShowWindow(window, win32::SW_MINIMIZE);
+    }
+}
+
+pub fn restore(window: win32::HWND) {
+    unsafe {
+        let _ = win32::ShowWindow(window, win32::SW_RESTORE);
+    }
+}
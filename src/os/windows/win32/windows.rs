@@ -0,0 +1,99 @@
+use super::api as win32;
+use super::pid_to_exe::pid_to_exe;
+use crate::models::WindowInfo;
+
+fn is_main_window(window: win32::HWND) -> bool {
+    fn is_tool_window(window: win32::HWND) -> bool {
+        let ex_style = unsafe { win32::GetWindowLongW(window, win32::GWL_EXSTYLE) };
+        ex_style as u32 & win32::WS_EX_TOOLWINDOW.0 != 0
+    }
+    unsafe {
+        win32::IsWindowVisible(window).as_bool() // is visible
+            && win32::GetWindowTextLengthW(window) > 0 // has title
+            && win32::GetParent(window).map_or(true, |p| p.is_invalid()) // does not have parent
+            && !is_tool_window(window)
+    }
+}
+
+pub fn collect_main_windows() -> windows::core::Result<Vec<win32::HWND>> {
+    extern "system" fn collect_window_callback(
+        window: win32::HWND,
+        lparam: win32::LPARAM,
+    ) -> windows::core::BOOL {
+        unsafe {
+            let windows = &mut *(lparam.0 as *mut Vec<win32::HWND>);
+            windows.push(window);
+            true.into() // continue enumeration
+        }
+    }
+
+    let mut windows = Vec::new();
+    let lparam = win32::LPARAM(&mut windows as *mut _ as isize);
+    unsafe {
+        win32::EnumWindows(Some(collect_window_callback), lparam)?;
+    }
+    windows.retain(|&window| is_main_window(window));
+    Ok(windows)
+}
+
+pub(super) fn find_matching_windows(
+    windows: &[win32::HWND],
+    target_exe: &str,
+) -> windows::core::Result<Vec<win32::HWND>> {
+    let mut matching = Vec::new();
+    for &window in windows {
+        if window_exe(window)?.to_lowercase() == target_exe.to_lowercase() {
+            matching.push(window);
+        }
+    }
+    Ok(matching)
+}
+
+pub fn foreground_window() -> Option<win32::HWND> {
+    let window = unsafe { win32::GetForegroundWindow() };
+    (!window.is_invalid()).then_some(window)
+}
+
+pub fn window_exe(window: win32::HWND) -> windows::core::Result<String> {
+    let mut process_id = 0u32;
+    unsafe {
+        // kernel lookup, inexpensive
+        win32::GetWindowThreadProcessId(window, Some(&mut process_id));
+    }
+    pid_to_exe(process_id)
+}
+
+pub fn current_exe() -> windows::core::Result<String> {
+    pid_to_exe(std::process::id())
+}
+
+// EnumWindows yields windows in z-order, so the order already reflects focus recency
+pub(super) fn describe_windows(
+    windows: &[win32::HWND],
+) -> windows::core::Result<Vec<WindowInfo<win32::HWND>>> {
+    let cursor_monitor = unsafe {
+        let mut cursor = win32::POINT::default();
+        win32::GetCursorPos(&mut cursor)?;
+        win32::MonitorFromPoint(cursor, win32::MONITOR_DEFAULTTONEAREST)
+    };
+    Ok(windows
+        .iter()
+        .map(|&window| unsafe {
+            WindowInfo {
+                handle: window,
+                title: window_title(window),
+                minimized: win32::IsIconic(window).as_bool(),
+                on_cursor_monitor: win32::MonitorFromWindow(
+                    window,
+                    win32::MONITOR_DEFAULTTONEAREST,
+                ) == cursor_monitor,
+            }
+        })
+        .collect())
+}
+
+pub fn window_title(window: win32::HWND) -> String {
+    let mut buffer = [0u16; 512];
+    let len = unsafe { win32::GetWindowTextW(window, &mut buffer) };
+    String::from_utf16_lossy(&buffer[..len.max(0) as usize])
+}
@@ -0,0 +1,52 @@
+use super::win32::{self, HWND};
+use crate::os::WindowModel;
+
+#[derive(Default)]
+pub struct WindowManager;
+
+impl WindowModel for WindowManager {
+    type Window = HWND;
+
+    fn visible_windows(&self) -> anyhow::Result<Vec<(String, HWND)>> {
+        let mut visible = Vec::new();
+        for window in win32::collect_main_windows()? {
+            if !win32::is_minimized(window) {
+                visible.push((win32::window_exe(window)?, window));
+            }
+        }
+        Ok(visible)
+    }
+
+    fn frontmost_app(&self) -> Option<String> {
+        win32::foreground_window().and_then(|w| win32::window_exe(w).ok())
+    }
+
+    fn frontmost_title(&self) -> Option<String> {
+        win32::foreground_window().map(win32::window_title)
+    }
+
+    fn own_app(&self) -> Option<String> {
+        win32::current_exe().ok()
+    }
+
+    fn running_apps(&self) -> anyhow::Result<Vec<String>> {
+        let mut running = Vec::new();
+        for window in win32::collect_main_windows()? {
+            let exe = win32::window_exe(window)?;
+            if !running.contains(&exe) {
+                running.push(exe);
+            }
+        }
+        Ok(running)
+    }
+
+    fn hide(&mut self, window: &HWND) -> anyhow::Result<()> {
+        win32::minimize(*window);
+        Ok(())
+    }
+
+    fn restore(&mut self, window: &HWND) -> anyhow::Result<()> {
+        win32::restore(*window);
+        Ok(())
+    }
+}
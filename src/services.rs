@@ -1,8 +1,14 @@
 mod action;
 mod config;
+mod context;
+mod current_group;
+mod focus;
 mod group;
 mod hotkey;
+mod outcome;
+mod state;
 
 pub use action::ActionService;
 pub use config::ConfigService;
-pub use hotkey::{HotkeyService, SharedSender};
+pub use hotkey::{BindError, HotkeyError, HotkeyService, SharedSender};
+pub use outcome::{ActionOutcome, ActionReport, OutcomeHistory};
@@ -1,18 +1,354 @@
-use crate::models::Action;
-use crate::services::ConfigService;
-use crate::services::group::GroupService;
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Instant;
 
-#[derive(Default)]
-pub struct ActionService {
-    group_service: GroupService,
+use log::{info, warn};
+use uuid::Uuid;
+
+use crate::models::{Action, Config, Group, Identifiable, LaunchOptions, WindowPolicy};
+use crate::os::{App, Openable, Shell, ShellBehavior, WindowManager, WindowModel};
+use crate::services::current_group::CurrentGroupService;
+use crate::services::group::{ActivationError, GroupService, MemberOpener, SystemOpener};
+use crate::services::outcome::{ActionOutcome, ActionReport};
+
+/// Cheap to clone, so every action can run on its own while others wait for a delay
+pub struct ActionService<M: WindowModel = WindowManager, O: MemberOpener = SystemOpener> {
+    group_service: Rc<GroupService<M, O>>,
+    current_group_service: Rc<RefCell<CurrentGroupService>>,
+    /// Pausing needs the hotkeys, which belong to the config service
+    toggle_paused: Rc<dyn Fn()>,
+}
+
+// not derived, that would require the window model and the opener to be cloneable too
+impl<M: WindowModel, O: MemberOpener> Clone for ActionService<M, O> {
+    fn clone(&self) -> Self {
+        Self {
+            group_service: self.group_service.clone(),
+            current_group_service: self.current_group_service.clone(),
+            toggle_paused: self.toggle_paused.clone(),
+        }
+    }
 }
 
 impl ActionService {
-    pub fn execute(&mut self, config_service: &ConfigService, action: &Action) {
+    pub fn new(toggle_paused: impl Fn() + 'static) -> Self {
+        Self {
+            group_service: Rc::default(),
+            current_group_service: Rc::default(),
+            toggle_paused: Rc::new(toggle_paused),
+        }
+    }
+}
+
+impl<M: WindowModel, O: MemberOpener> ActionService<M, O> {
+    /// Runs the action against a snapshot of the config, so the UI can change it meanwhile
+    pub async fn execute(&self, config: &Config, action: &Action) -> ActionReport {
+        let outcome = self.run(config, action).await;
+        let report = ActionReport {
+            label: config.describe(action),
+            outcome,
+            at: Instant::now(),
+        };
+        match report.outcome.is_failure() {
+            true => warn!("{report}"),
+            false => info!("{report}"),
+        }
+        report
+    }
+
+    async fn run(&self, config: &Config, action: &Action) -> ActionOutcome {
         match action {
-            Action::OpenGroup { group_id } => self.group_service.open(config_service, *group_id),
+            Action::OpenGroup { group_id } => self.open_group(config, *group_id),
+            Action::LaunchGroup { group_id } => {
+                self.leave_current(config);
+                match self.group_service.launch_all(config, *group_id).await {
+                    Ok(report) => {
+                        self.current_group_service.borrow_mut().record(*group_id);
+                        report.into_outcome()
+                    }
+                    Err(e) => ActionOutcome::failed(e),
+                }
+            }
+            Action::RestoreHidden { group_id } => {
+                self.group_service.restore(*group_id);
+                ActionOutcome::Success
+            }
+            Action::PreviousGroup => self.open_previous(config),
+            Action::ToggleHotkeysPaused => {
+                (self.toggle_paused)();
+                ActionOutcome::Success
+            }
+            Action::OpenApp { app_id } => {
+                let app = App::from(app_id.clone());
+                let result = app.open(&WindowPolicy::default(), &LaunchOptions::default());
+                ActionOutcome::from_result(result)
+            }
+            Action::OpenUrl { url } => ActionOutcome::from_result(Shell::open_url(url)),
+            Action::RunCommand { command } => {
+                ActionOutcome::from_result(Shell::run_command(command))
+            }
+            Action::ActivateGroupByName { name } => {
+                let group = config
+                    .groups()
+                    .iter()
+                    .find(|g| g.name.eq_ignore_ascii_case(name));
+                match group {
+                    Some(group) => self.open_group(config, group.id()),
+                    None => ActionOutcome::failed(format!("There is no group named '{name}'")),
+                }
+            }
+            Action::Binding { binding_id } => match config.binding(*binding_id) {
+                Some(binding) => Box::pin(self.run(config, &binding.action)).await,
+                None => ActionOutcome::failed("The binding no longer exists"),
+            },
+            Action::ChordKey { hotkey } | Action::TriggerKey { hotkey, .. } => {
+                ActionOutcome::failed(format!("{hotkey} has to be resolved by the hotkeys"))
+            }
+            Action::Sequence { steps } => {
+                let mut outcomes = Vec::new();
+                for step in steps {
+                    if !step.delay().is_zero() {
+                        tokio::time::sleep(step.delay()).await;
+                    }
+                    outcomes.push(Box::pin(self.run(config, &step.action)).await);
+                }
+                ActionOutcome::combine(outcomes)
+            }
             #[cfg(test)]
-            Action::Mock(_) => {}
+            Action::Mock(_) => ActionOutcome::Success,
+        }
+    }
+
+    /// The group the frontmost app belongs to, the groups are expected to be resolved
+    pub fn current_group(&self, resolved_groups: &[Group]) -> Option<Uuid> {
+        let frontmost_app = self.group_service.frontmost_app()?;
+        let candidates: Vec<Uuid> = resolved_groups
+            .iter()
+            .filter(|g| g.contains_app(&frontmost_app))
+            .map(|g| g.id())
+            .collect();
+        self.current_group_service.borrow().current(&candidates)
+    }
+
+    fn open_group(&self, config: &Config, group_id: Uuid) -> ActionOutcome {
+        self.leave_current(config);
+        let result = self.group_service.open(config, group_id);
+        self.finish_activation(group_id, result)
+    }
+
+    fn open_previous(&self, config: &Config) -> ActionOutcome {
+        let current = self.current_group(&config.resolved_groups());
+        let previous = self
+            .current_group_service
+            .borrow()
+            .previous(current)
+            .cloned();
+        let Some(previous) = previous else {
+            return ActionOutcome::skipped("No other group was activated yet");
+        };
+        let Some(group) = config.group(previous.group_id) else {
+            return ActionOutcome::failed("The previous group was removed");
+        };
+        info!(
+            "Returning to group {group}, activated {}s ago",
+            previous.at.elapsed().as_secs()
+        );
+        self.leave_current(config);
+        let result = match previous.app_id {
+            Some(app_id) => self
+                .group_service
+                .open_member(config, previous.group_id, &app_id),
+            None => self.group_service.open(config, previous.group_id),
+        };
+        self.finish_activation(previous.group_id, result)
+    }
+
+    fn finish_activation(
+        &self,
+        group_id: Uuid,
+        result: Result<(), ActivationError>,
+    ) -> ActionOutcome {
+        match result {
+            Ok(()) => {
+                self.current_group_service.borrow_mut().record(group_id);
+                ActionOutcome::Success
+            }
+            Err(e) => ActionOutcome::failed(e),
+        }
+    }
+
+    fn leave_current(&self, config: &Config) {
+        let Some(group_id) = self.current_group(&config.resolved_groups()) else {
+            return;
+        };
+        if let Some(app_id) = self.group_service.frontmost_app() {
+            self.current_group_service
+                .borrow_mut()
+                .leave(group_id, app_id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{AppEntry, Member, Step};
+    use crate::services::focus::tests::FakeWindowModel;
+    use crate::services::group::tests::FakeOpener;
+
+    /// Nothing is opened for real, the opener only records what it was asked to open
+    fn setup_service() -> ActionService<FakeWindowModel, FakeOpener> {
+        ActionService {
+            group_service: Rc::default(),
+            current_group_service: Rc::default(),
+            toggle_paused: Rc::new(|| {}),
         }
     }
+
+    fn step(action: Action) -> Step {
+        Step {
+            action,
+            delay_ms: 0,
+        }
+    }
+
+    fn setup_binding(config: &mut Config, action: Action) -> Action {
+        let binding_id = config.add_binding("Test".to_string());
+        config.set_binding_action(binding_id, action);
+        Action::Binding { binding_id }
+    }
+
+    #[tokio::test]
+    async fn binding_runs_its_action() {
+        // Arrange
+        let service = setup_service();
+        let mut config = Config::default();
+        let binding = setup_binding(&mut config, Action::Mock("binding"));
+
+        // Act
+        let report = service.execute(&config, &binding).await;
+
+        // Assert
+        assert_eq!(report.outcome, ActionOutcome::Success);
+    }
+
+    #[tokio::test]
+    async fn removed_binding_fails() {
+        // Arrange
+        let service = setup_service();
+        let mut config = Config::default();
+        let binding = setup_binding(&mut config, Action::Mock("binding"));
+        let Action::Binding { binding_id } = binding else {
+            unreachable!()
+        };
+        config.remove_binding(binding_id);
+
+        // Act
+        let report = service.execute(&config, &binding).await;
+
+        // Assert
+        assert!(report.outcome.is_failure());
+    }
+
+    #[tokio::test]
+    async fn sequence_runs_every_step() {
+        // Arrange
+        let service = setup_service();
+        let config = Config::default();
+        let sequence = Action::Sequence {
+            steps: vec![step(Action::Mock("first")), step(Action::Mock("second"))],
+        };
+
+        // Act
+        let report = service.execute(&config, &sequence).await;
+
+        // Assert
+        assert_eq!(report.outcome, ActionOutcome::Success);
+    }
+
+    #[tokio::test]
+    async fn sequence_with_missing_group_is_partial() {
+        // Arrange
+        let service = setup_service();
+        let config = Config::default();
+        let missing = Action::ActivateGroupByName {
+            name: "Missing".to_string(),
+        };
+        let sequence = Action::Sequence {
+            steps: vec![step(Action::Mock("first")), step(missing.clone())],
+        };
+
+        // Act
+        let missing_report = service.execute(&config, &missing).await;
+        let sequence_report = service.execute(&config, &sequence).await;
+
+        // Assert
+        assert_eq!(
+            missing_report.outcome,
+            ActionOutcome::failed("There is no group named 'Missing'")
+        );
+        assert_eq!(
+            sequence_report.outcome,
+            ActionOutcome::Partial {
+                failures: vec!["There is no group named 'Missing'".to_string()]
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn open_group_opens_its_member() {
+        // Arrange
+        let service = setup_service();
+        let mut config = Config::default();
+        let group_id = config.add_group("Work".to_string());
+        let editor = App::from("editor".to_string());
+        config.add_member(group_id, Member::App(AppEntry::new(editor)));
+
+        // Act
+        let report = service
+            .execute(&config, &Action::OpenGroup { group_id })
+            .await;
+
+        // Assert
+        assert_eq!(report.outcome, ActionOutcome::Success);
+        assert_eq!(
+            *service.group_service.opener().opened.borrow(),
+            vec!["editor"]
+        );
+    }
+
+    #[tokio::test]
+    async fn previous_group_without_history_is_skipped() {
+        // Arrange
+        let service = setup_service();
+        let config = Config::default();
+
+        // Act
+        let report = service.execute(&config, &Action::PreviousGroup).await;
+
+        // Assert
+        assert_eq!(
+            report.outcome,
+            ActionOutcome::skipped("No other group was activated yet")
+        );
+    }
+
+    #[tokio::test]
+    async fn removed_previous_group_fails() {
+        // Arrange
+        let service = setup_service();
+        let mut config = Config::default();
+        let group_id = config.add_group("Removed".to_string());
+        service.current_group_service.borrow_mut().record(group_id);
+        config.remove_group(group_id);
+
+        // Act
+        let report = service.execute(&config, &Action::PreviousGroup).await;
+
+        // Assert
+        assert_eq!(
+            report.outcome,
+            ActionOutcome::failed("The previous group was removed")
+        );
+    }
 }
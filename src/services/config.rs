@@ -1,12 +1,26 @@
+use std::cell::OnceCell;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use log::warn;
 use uuid::Uuid;
 
-use crate::models::{Action, Config, Group, Hotkey};
-use crate::os::App;
-use crate::services::{HotkeyService, SharedSender};
+use crate::models::{
+    Action, Bindable, Binding, Chord, Config, Group, GroupMode, GroupRule, Hotkey, HotkeyContext,
+    LaunchOptions, Member, MemberFlags, Settings, Shortcut, Trigger, WindowPolicy,
+};
+use crate::os::{App, AppCatalog, Catalog};
+use crate::services::context::ContextService;
+use crate::services::state::{SavedState, StateStore};
+use crate::services::{BindError, HotkeyError, HotkeyService, SharedSender};
 
 pub struct ConfigService {
-    config: Config,
+    config: Arc<Config>, // shared with running actions, copied on write while they hold it
+    resolved_groups: OnceCell<Vec<Group>>, // polled for the current group, reset on changes
     hotkey_service: HotkeyService,
+    context_service: ContextService,
+    state_store: StateStore,
 }
 
 impl ConfigService {
@@ -14,48 +28,336 @@ impl ConfigService {
         record_registered_sender: SharedSender<Hotkey>,
         action_sender: SharedSender<Action>,
     ) -> Self {
+        let state_store = StateStore::default();
+        let state = state_store.load();
+        let mut config = Config::default();
+        // nothing is registered yet, the hotkeys are bound in line with it as they are set
+        config.set_hotkeys_paused(state.hotkeys_paused);
         Self {
-            config: Config::default(),
+            config: Arc::new(config),
+            resolved_groups: OnceCell::new(),
             hotkey_service: HotkeyService::new(record_registered_sender, action_sender),
+            context_service: ContextService::default(),
+            state_store,
         }
     }
 
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    /// The config as it is now, for an action which may outlive the next change
+    pub fn snapshot(&self) -> Arc<Config> {
+        self.config.clone()
+    }
+
+    fn config_mut(&mut self) -> &mut Config {
+        self.resolved_groups.take();
+        Arc::make_mut(&mut self.config)
+    }
+
+    /// Every group as it is activated, kept until the config changes
+    pub fn resolved_groups(&self) -> &[Group] {
+        self.resolved_groups
+            .get_or_init(|| self.config.resolved_groups())
+    }
+
     pub fn groups(&self) -> &Vec<Group> {
         self.config.groups()
     }
 
+    pub fn settings(&self) -> &Settings {
+        self.config.settings()
+    }
+
     pub fn group(&self, group_id: Uuid) -> Option<&Group> {
         self.config.group(group_id)
     }
 
+    pub fn standalone_bindings(&self) -> &Vec<Binding> {
+        self.config.standalone_bindings()
+    }
+
+    pub fn binding(&self, binding_id: Uuid) -> Option<&Binding> {
+        self.config.binding(binding_id)
+    }
+
+    pub fn add_binding(&mut self, name: String) -> Uuid {
+        self.config_mut().add_binding(name)
+    }
+
+    pub fn remove_binding(&mut self, binding_id: Uuid) {
+        let action = Action::Binding { binding_id };
+        self.set_hotkey(action.clone(), None); // frees the hotkey
+        self.set_chord(action, None);
+        self.config_mut().remove_binding(binding_id)
+    }
+
+    pub fn set_binding_name(&mut self, binding_id: Uuid, name: String) {
+        self.config_mut().set_binding_name(binding_id, name)
+    }
+
+    pub fn set_binding_enabled(&mut self, binding_id: Uuid, enabled: bool) {
+        let actions = [Action::Binding { binding_id }];
+        self.hotkey_service
+            .set_enabled(&self.config, &actions, enabled);
+        self.config_mut().set_binding_enabled(binding_id, enabled)
+    }
+
+    pub fn set_binding_action(&mut self, binding_id: Uuid, action: Action) {
+        self.config_mut().set_binding_action(binding_id, action)
+    }
+
     pub fn add_group(&mut self, name: String) -> Uuid {
-        self.config.add_group(name)
+        self.config_mut().add_group(name)
     }
 
     pub fn remove_group(&mut self, group_id: Uuid) {
-        self.config.remove_group(group_id)
+        let Some(group) = self.config.group(group_id) else {
+            return;
+        };
+        let actions: Vec<Action> = group.bindings().into_iter().map(|(_, a)| a).collect();
+        for action in actions {
+            self.set_hotkey(action.clone(), None); // frees the hotkeys
+            self.set_chord(action, None);
+        }
+        self.config_mut().remove_group(group_id)
     }
 
     pub fn set_name(&mut self, group_id: Uuid, name: String) {
-        self.config.set_name(group_id, name)
+        self.config_mut().set_name(group_id, name)
+    }
+
+    pub fn set_mode(&mut self, group_id: Uuid, mode: GroupMode) {
+        self.config_mut().set_mode(group_id, mode)
+    }
+
+    pub fn set_window_policy(&mut self, group_id: Uuid, window_policy: WindowPolicy) {
+        self.config_mut().set_window_policy(group_id, window_policy)
+    }
+
+    pub fn add_member(&mut self, group_id: Uuid, member: Member) {
+        self.config_mut().add_member(group_id, member)
+    }
+
+    pub fn remove_member(&mut self, group_id: Uuid, member_id: String) {
+        self.config_mut().remove_member(group_id, member_id)
     }
 
-    pub fn add_app(&mut self, group_id: Uuid, app: App) {
-        self.config.add_app(group_id, app)
+    pub fn move_member(&mut self, group_id: Uuid, member_id: &str, before_id: Option<&str>) {
+        self.config_mut()
+            .move_member(group_id, member_id, before_id)
     }
 
-    pub fn remove_app(&mut self, group_id: Uuid, app_id: String) {
-        self.config.remove_app(group_id, app_id)
+    pub fn set_launch_options(
+        &mut self,
+        group_id: Uuid,
+        app_id: &str,
+        launch_options: LaunchOptions,
+    ) {
+        self.config_mut()
+            .set_launch_options(group_id, app_id, launch_options)
     }
 
-    pub fn set_hotkey(&mut self, group_id: Uuid, hotkey: Option<Hotkey>) -> Option<Action> {
-        let (existing_hotkey, action) = self.config.get_binding(group_id).unwrap();
-        let conflict =
+    /// Returns the conflicting action. A hotkey the OS refused is kept, see `hotkey_error`.
+    pub fn set_hotkey(&mut self, action: Action, hotkey: Option<Hotkey>) -> Option<Action> {
+        let existing_hotkey = self.shortcut(&action)?.hotkey;
+        let result =
             self.hotkey_service
-                .bind_hotkey(&self.config, hotkey, existing_hotkey, action);
+                .bind_hotkey(&self.config, hotkey, existing_hotkey, action.clone());
+        match result {
+            Err(HotkeyError::Conflict(conflict)) => Some(conflict),
+            _ => {
+                self.update_shortcut(|config| config.set_hotkey(&action, hotkey));
+                None
+            }
+        }
+    }
+
+    /// Why the hotkey of the action, or the leader of its chord, is not registered
+    pub fn hotkey_error(&self, action: &Action) -> Option<&BindError> {
+        let leader = self.config.get_chord(action).map(|chord| chord.leader());
+        let hotkey = self.config.get_hotkey(action).or(leader)?;
+        self.hotkey_service.failure(hotkey)
+    }
+
+    /// Returns the action whose chord or hotkey can't be told apart from the new chord
+    pub fn set_chord(&mut self, action: Action, chord: Option<Chord>) -> Option<Action> {
+        let existing_chord = self.shortcut(&action)?.chord.clone();
+        let conflict = self.hotkey_service.bind_chord(
+            &self.config,
+            chord.clone(),
+            existing_chord,
+            action.clone(),
+        );
         if conflict.is_none() {
-            self.config.set_hotkey(group_id, hotkey);
+            self.update_shortcut(|config| config.set_chord(&action, chord));
         }
         conflict
     }
+
+    pub fn set_trigger(&mut self, action: Action, trigger: Trigger) -> Option<Action> {
+        self.shortcut(&action)?;
+        let result = self
+            .hotkey_service
+            .bind_trigger(&self.config, action.clone(), trigger);
+        match result {
+            Err(HotkeyError::Conflict(conflict)) => Some(conflict),
+            _ => {
+                self.update_shortcut(|config| config.set_trigger(&action, trigger));
+                None
+            }
+        }
+    }
+
+    /// General-purpose actions only get a shortcut through a binding, so nothing is bound
+    /// for them
+    fn shortcut(&self, action: &Action) -> Option<&Shortcut> {
+        let shortcut = self.config.shortcut(action);
+        if shortcut.is_none() {
+            warn!("'{action}' only gets a shortcut through a binding");
+        }
+        shortcut
+    }
+
+    fn update_shortcut(&mut self, update: impl FnOnce(&mut Config) -> anyhow::Result<()>) {
+        if let Err(e) = update(self.config_mut()) {
+            warn!("Could not change the shortcut: {e}");
+        }
+    }
+
+    /// Kept across restarts
+    pub fn set_hotkeys_paused(&mut self, paused: bool) {
+        self.hotkey_service.set_paused(&self.config, paused);
+        self.config_mut().set_hotkeys_paused(paused);
+        self.state_store.save(&SavedState {
+            hotkeys_paused: paused,
+        });
+    }
+
+    pub fn toggle_hotkeys_paused(&mut self) {
+        let paused = !self.config.settings().hotkeys_paused;
+        self.set_hotkeys_paused(paused);
+    }
+
+    /// Actions inactive in front of the current app are skipped
+    pub fn resolve_action(&mut self, action: Action) -> Option<Action> {
+        let action = self.hotkey_service.resolve(&self.config, action)?;
+        self.context_service
+            .allows(&self.config, &action)
+            .then_some(action)
+    }
+
+    pub fn set_context(&mut self, action: Action, context: HotkeyContext) {
+        if self.shortcut(&action).is_none() {
+            return;
+        }
+        self.hotkey_service
+            .bind_context(&self.config, &action, &context);
+        self.update_shortcut(|config| config.set_context(&action, context));
+    }
+
+    /// The frontmost app, unless the hotkeys are registered for it already
+    pub fn frontmost_change(&self) -> Option<Option<String>> {
+        if !self.config.has_contexts() {
+            return None; // spares looking up the frontmost app
+        }
+        let frontmost = self.context_service.frontmost_app();
+        (frontmost.as_deref() != self.hotkey_service.frontmost()).then_some(frontmost)
+    }
+
+    /// Registers only the hotkeys active in front of the app
+    pub fn set_frontmost(&mut self, frontmost: Option<String>) {
+        self.hotkey_service.set_frontmost(&self.config, frontmost)
+    }
+
+    pub fn key_deadline(&self) -> Option<Instant> {
+        self.hotkey_service.deadline()
+    }
+
+    pub fn expire_keys(&mut self) -> Vec<Action> {
+        self.hotkey_service.expire(&self.config)
+    }
+
+    pub fn set_group_enabled(&mut self, group_id: Uuid, enabled: bool) {
+        let Some(group) = self.config.group(group_id) else {
+            return;
+        };
+        let actions: Vec<Action> = group.bindings().into_iter().map(|(_, a)| a).collect();
+        self.hotkey_service
+            .set_enabled(&self.config, &actions, enabled);
+        self.config_mut().set_group_enabled(group_id, enabled)
+    }
+
+    pub fn set_exclusive(&mut self, group_id: Uuid, exclusive: bool) {
+        self.config_mut().set_exclusive(group_id, exclusive)
+    }
+
+    pub fn set_launch_stagger(&mut self, group_id: Uuid, stagger: Duration) {
+        self.config_mut().set_launch_stagger(group_id, stagger)
+    }
+
+    pub fn set_primary_member(&mut self, group_id: Uuid, member_id: Option<String>) {
+        self.config_mut().set_primary_member(group_id, member_id)
+    }
+
+    pub fn children(&self, group_id: Uuid) -> impl Iterator<Item = &Group> {
+        self.config.children(group_id)
+    }
+
+    pub fn can_include(&self, group_id: Uuid, other_id: Uuid) -> bool {
+        self.config.can_include(group_id, other_id)
+    }
+
+    pub fn set_parent(&mut self, group_id: Uuid, parent_id: Option<Uuid>) -> bool {
+        self.config_mut().set_parent(group_id, parent_id)
+    }
+
+    pub fn set_include_children(&mut self, group_id: Uuid, include_children: bool) {
+        self.config_mut()
+            .set_include_children(group_id, include_children)
+    }
+
+    pub fn set_member_flags(&mut self, group_id: Uuid, member_id: &str, flags: MemberFlags) {
+        self.config_mut()
+            .set_member_flags(group_id, member_id, flags)
+    }
+
+    pub fn set_rule(&mut self, group_id: Uuid, rule: Option<GroupRule>) {
+        self.config_mut().set_rule(group_id, rule)
+    }
+
+    /// Scans the installed apps off the UI thread, which takes a while
+    pub async fn scan_installed_apps() -> Vec<(App, PathBuf)> {
+        tokio::task::spawn_blocking(installed_apps)
+            .await
+            .unwrap_or_else(|e| {
+                warn!("Could not list installed apps: {e}");
+                Vec::new()
+            })
+    }
+
+    /// Re-evaluates the rules of dynamic groups against a new scan
+    pub fn set_installed_apps(&mut self, installed_apps: Vec<(App, PathBuf)>) {
+        self.config_mut().set_installed_apps(installed_apps)
+    }
+
+    pub fn pin_member(&mut self, group_id: Uuid, member_id: &str) {
+        self.config_mut().pin_member(group_id, member_id)
+    }
+
+    pub fn exclude_member(&mut self, group_id: Uuid, member_id: &str) {
+        self.config_mut().exclude_member(group_id, member_id)
+    }
+
+    pub fn clear_exclusions(&mut self, group_id: Uuid) {
+        self.config_mut().clear_exclusions(group_id)
+    }
+}
+
+fn installed_apps() -> Vec<(App, PathBuf)> {
+    Catalog::installed_apps().unwrap_or_else(|e| {
+        warn!("Could not list installed apps: {e}");
+        Vec::new()
+    })
 }
@@ -0,0 +1,94 @@
+use log::info;
+
+use crate::models::{Action, Config};
+use crate::os::{WindowManager, WindowModel};
+
+/// Checks whether a hotkey may run its action in front of the current app.
+/// Hotkeys are unbound in other contexts, an action which still slips through is skipped.
+pub struct ContextService<M: WindowModel = WindowManager> {
+    model: M,
+}
+
+impl<M: WindowModel + Default> Default for ContextService<M> {
+    fn default() -> Self {
+        Self {
+            model: M::default(),
+        }
+    }
+}
+
+impl<M: WindowModel> ContextService<M> {
+    pub fn frontmost_app(&self) -> Option<String> {
+        self.model.frontmost_app()
+    }
+
+    pub fn allows(&self, config: &Config, action: &Action) -> bool {
+        let context = config.context(action);
+        if context.is_empty() {
+            return true; // spares looking up the frontmost app
+        }
+        let frontmost_app = self.model.frontmost_app();
+        let allowed = context.allows(frontmost_app.as_deref());
+        if !allowed {
+            let app = frontmost_app.as_deref().unwrap_or("no app");
+            info!("Skipping {action}, it is inactive in front of {app}");
+        }
+        allowed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::HotkeyContext;
+    use crate::services::focus::tests::FakeWindowModel;
+
+    fn setup_service(app: &str) -> ContextService<FakeWindowModel> {
+        ContextService {
+            model: FakeWindowModel {
+                frontmost: Some(app.to_string()),
+                ..Default::default()
+            },
+        }
+    }
+
+    fn setup_config() -> (Config, Action) {
+        let mut config = Config::default();
+        let group_id = config.add_group("Browsers".to_string());
+        let action = Action::OpenGroup { group_id };
+        let context = HotkeyContext {
+            only_in: Vec::new(),
+            excluded: vec!["editor".to_string()],
+        };
+        config.set_context(&action, context).unwrap();
+        (config, action)
+    }
+
+    #[test]
+    fn skips_excluded_app() {
+        // Arrange
+        let service = setup_service("editor");
+        let (config, action) = setup_config();
+
+        // Act
+        let allowed = service.allows(&config, &action);
+
+        // Assert
+        assert!(!allowed);
+    }
+
+    #[test]
+    fn allows_other_apps() {
+        // Arrange
+        let service = setup_service("chat");
+        let (config, action) = setup_config();
+
+        // Act
+        let allowed = service.allows(&config, &action);
+        let unlimited = service.allows(&config, &Action::PreviousGroup);
+
+        // Assert
+        assert!(allowed);
+        assert!(unlimited);
+    }
+}
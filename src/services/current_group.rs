@@ -0,0 +1,156 @@
+use std::time::Instant;
+
+use uuid::Uuid;
+
+const HISTORY_LIMIT: usize = 32;
+
+#[derive(Debug, Clone)]
+pub struct Activation {
+    pub group_id: Uuid,
+    pub app_id: Option<String>, // the app in front when the group was left
+    pub at: Instant,
+}
+
+/// Knows which group is active, shared by cycling, "previous group" and the sidebar.
+#[derive(Default)]
+pub struct CurrentGroupService {
+    history: Vec<Activation>,
+}
+
+impl CurrentGroupService {
+    /// The active group out of the groups containing the frontmost app (in config order).
+    /// An app in several groups belongs to the one activated last, or else to the first one.
+    pub fn current(&self, candidates: &[Uuid]) -> Option<Uuid> {
+        self.history
+            .iter()
+            .rev()
+            .map(|a| a.group_id)
+            .find(|id| candidates.contains(id))
+            .or_else(|| candidates.first().copied())
+    }
+
+    /// The most recent activation of another group than the current one
+    pub fn previous(&self, current: Option<Uuid>) -> Option<&Activation> {
+        self.history
+            .iter()
+            .rev()
+            .find(|a| Some(a.group_id) != current)
+    }
+
+    pub fn record(&mut self, group_id: Uuid) {
+        if let Some(last) = self.history.last_mut()
+            && last.group_id == group_id
+        {
+            last.at = Instant::now(); // cycling within a group is not a new activation
+            return;
+        }
+        self.history.retain(|a| a.group_id != group_id);
+        self.history.push(Activation {
+            group_id,
+            app_id: None,
+            at: Instant::now(),
+        });
+        if self.history.len() > HISTORY_LIMIT {
+            self.history.remove(0);
+        }
+    }
+
+    /// Remembers the app in front of a group that is being left, to return to it later
+    pub fn leave(&mut self, group_id: Uuid, app_id: String) {
+        if let Some(activation) = self.history.iter_mut().find(|a| a.group_id == group_id) {
+            activation.app_id = Some(app_id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn setup_service(activated: &[Uuid]) -> CurrentGroupService {
+        let mut service = CurrentGroupService::default();
+        for group_id in activated {
+            service.record(*group_id);
+        }
+        service
+    }
+
+    #[test]
+    fn current_prefers_last_activated() {
+        // Arrange
+        let (a, b, c) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let service = setup_service(&[b, a, c]);
+
+        // Act
+        let current = service.current(&[b, a]);
+
+        // Assert
+        assert_eq!(current, Some(a));
+    }
+
+    #[test]
+    fn current_falls_back_to_config_order() {
+        // Arrange
+        let (a, b, c) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let service = setup_service(&[c]);
+
+        // Act
+        let current = service.current(&[b, a]);
+
+        // Assert
+        assert_eq!(current, Some(b));
+    }
+
+    #[test]
+    fn current_none_outside_groups() {
+        // Arrange
+        let service = setup_service(&[Uuid::new_v4()]);
+
+        // Act
+        let current = service.current(&[]);
+
+        // Assert
+        assert_eq!(current, None);
+    }
+
+    #[test]
+    fn previous_skips_current() {
+        // Arrange
+        let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+        let mut service = setup_service(&[a, b, a]);
+        service.leave(b, "editor".to_string());
+
+        // Act
+        let previous = service.previous(Some(a));
+
+        // Assert
+        let previous = previous.unwrap();
+        assert_eq!(previous.group_id, b);
+        assert_eq!(previous.app_id.as_deref(), Some("editor"));
+    }
+
+    #[test]
+    fn previous_none_with_empty_history() {
+        // Arrange
+        let service = setup_service(&[]);
+
+        // Act
+        let previous = service.previous(None);
+
+        // Assert
+        assert!(previous.is_none());
+    }
+
+    #[test]
+    fn previous_none_with_only_current() {
+        // Arrange
+        let a = Uuid::new_v4();
+        let service = setup_service(&[a, a]);
+
+        // Act
+        let previous = service.previous(Some(a));
+
+        // Assert
+        assert!(previous.is_none());
+    }
+}
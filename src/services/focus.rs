@@ -0,0 +1,213 @@
+use std::collections::HashMap;
+
+use log::warn;
+use uuid::Uuid;
+
+use crate::os::{WindowManager, WindowModel};
+
+/// Hides windows outside of exclusive groups and remembers them for restoring.
+pub struct FocusService<M: WindowModel = WindowManager> {
+    model: M,
+    hidden: HashMap<Uuid, Vec<M::Window>>,
+}
+
+impl<M: WindowModel + Default> Default for FocusService<M> {
+    fn default() -> Self {
+        Self::new(M::default())
+    }
+}
+
+impl<M: WindowModel> FocusService<M> {
+    pub fn new(model: M) -> Self {
+        Self {
+            model,
+            hidden: HashMap::new(),
+        }
+    }
+
+    pub fn hide_others(
+        &mut self,
+        group_id: Uuid,
+        is_member: impl Fn(&str) -> bool,
+    ) -> anyhow::Result<()> {
+        let hidden = self.hidden.entry(group_id).or_default();
+        for (app_id, window) in self.model.visible_windows()? {
+            if is_member(&app_id) || hidden.contains(&window) {
+                continue;
+            }
+            match self.model.hide(&window) {
+                Ok(()) => hidden.push(window),
+                Err(e) => warn!("Could not hide window of {app_id}: {e}"),
+            }
+        }
+        Ok(())
+    }
+
+    pub fn frontmost_app(&self) -> Option<String> {
+        self.model.frontmost_app()
+    }
+
+    pub fn frontmost_title(&self) -> Option<String> {
+        self.model.frontmost_title()
+    }
+
+    pub fn own_app(&self) -> Option<String> {
+        self.model.own_app()
+    }
+
+    pub fn running_apps(&self) -> Vec<String> {
+        self.model.running_apps().unwrap_or_else(|e| {
+            warn!("Could not list running apps: {e}");
+            Vec::new()
+        })
+    }
+
+    pub fn hide_app(&mut self, app_id: &str) -> anyhow::Result<()> {
+        for (id, window) in self.model.visible_windows()? {
+            if id.eq_ignore_ascii_case(app_id) {
+                self.model.hide(&window)?;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn restore(&mut self, group_id: Uuid) {
+        let hidden = self.hidden.remove(&group_id).unwrap_or_default();
+        // reverse order, so the previously topmost window ends up on top again
+        for window in hidden.iter().rev() {
+            if let Err(e) = self.model.restore(window) {
+                warn!("Could not restore window {window:?}: {e}");
+            }
+        }
+    }
+
+    #[cfg(test)]
+    pub fn model(&self) -> &M {
+        &self.model
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    /// Windows are numbered, and belong to the app whose id they are listed with
+    #[derive(Default)]
+    pub struct FakeWindowModel {
+        pub windows: Vec<(String, u32)>,
+        pub hidden: Vec<u32>,
+        pub frontmost: Option<String>,
+        pub own_app: Option<String>,
+    }
+
+    impl WindowModel for FakeWindowModel {
+        type Window = u32;
+
+        fn visible_windows(&self) -> anyhow::Result<Vec<(String, u32)>> {
+            Ok(self
+                .windows
+                .iter()
+                .filter(|(_, w)| !self.hidden.contains(w))
+                .cloned()
+                .collect())
+        }
+
+        fn frontmost_app(&self) -> Option<String> {
+            self.frontmost.clone()
+        }
+
+        fn frontmost_title(&self) -> Option<String> {
+            None
+        }
+
+        fn own_app(&self) -> Option<String> {
+            self.own_app.clone()
+        }
+
+        fn running_apps(&self) -> anyhow::Result<Vec<String>> {
+            Ok(self.windows.iter().map(|(id, _)| id.clone()).collect())
+        }
+
+        fn hide(&mut self, window: &u32) -> anyhow::Result<()> {
+            self.hidden.push(*window);
+            Ok(())
+        }
+
+        fn restore(&mut self, window: &u32) -> anyhow::Result<()> {
+            self.hidden.retain(|w| w != window);
+            Ok(())
+        }
+    }
+
+    fn setup_service() -> FocusService<FakeWindowModel> {
+        let mut service = FocusService::<FakeWindowModel>::default();
+        service.model.windows = vec![
+            ("editor".to_string(), 1),
+            ("browser".to_string(), 2),
+            ("chat".to_string(), 3),
+            ("editor".to_string(), 4),
+        ];
+        service
+    }
+
+    #[test]
+    fn hide_others_keeps_members() {
+        // Arrange
+        let mut service = setup_service();
+        let group_id = Uuid::new_v4();
+
+        // Act
+        service.hide_others(group_id, |id| id == "editor").unwrap();
+
+        // Assert
+        assert_eq!(service.model.hidden, vec![2, 3]);
+    }
+
+    #[test]
+    fn restore_exact() {
+        // Arrange
+        let mut service = setup_service();
+        service.model.hidden = vec![3]; // hidden by the user, not by us
+        let group_id = Uuid::new_v4();
+        service.hide_others(group_id, |id| id == "editor").unwrap();
+
+        // Act
+        service.restore(group_id);
+
+        // Assert
+        assert_eq!(service.model.hidden, vec![3]);
+    }
+
+    #[test]
+    fn restore_twice() {
+        // Arrange
+        let mut service = setup_service();
+        let group_id = Uuid::new_v4();
+        service.hide_others(group_id, |id| id == "editor").unwrap();
+        service.restore(group_id);
+        service.model.hidden = vec![2];
+
+        // Act
+        service.restore(group_id);
+
+        // Assert
+        assert_eq!(service.model.hidden, vec![2]);
+    }
+
+    #[test]
+    fn restore_only_own_group() {
+        // Arrange
+        let mut service = setup_service();
+        let writing = Uuid::new_v4();
+        let browsing = Uuid::new_v4();
+        service.hide_others(writing, |id| id == "editor").unwrap();
+        service.model.restore(&2).unwrap();
+        service.hide_others(browsing, |id| id == "browser").unwrap();
+
+        // Act
+        service.restore(browsing);
+
+        // Assert
+        assert_eq!(service.model.hidden, vec![3]);
+    }
+}
@@ -1,17 +1,494 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+use log::{info, warn};
 use uuid::Uuid;
 
-use crate::os::Openable;
-use crate::services::ConfigService;
+use crate::models::{AppEntry, Config, Group, GroupMode, Identifiable, Member};
+use crate::os::{App, OpenError, WindowManager, WindowModel};
+use crate::services::focus::FocusService;
+use crate::services::outcome::ActionOutcome;
+
+pub struct LaunchReport {
+    pub results: Vec<(Member, Result<(), OpenError>)>,
+}
+
+impl LaunchReport {
+    /// Partial if only some of the members could be launched
+    pub fn into_outcome(self) -> ActionOutcome {
+        let outcomes = self
+            .results
+            .into_iter()
+            .map(|(member, result)| match result {
+                Ok(()) => ActionOutcome::Success,
+                Err(e) => ActionOutcome::failed(format!("{member}: {e}")),
+            });
+        ActionOutcome::combine(outcomes)
+    }
+}
+
+#[derive(Debug)]
+pub enum ActivationError {
+    GroupNotFound,
+    EmptyGroup,
+    AllFailed(Vec<(Member, OpenError)>),
+}
+
+impl Display for ActivationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ActivationError::GroupNotFound => write!(f, "The group no longer exists"),
+            ActivationError::EmptyGroup => write!(f, "The group has no members"),
+            ActivationError::AllFailed(failures) => {
+                write!(f, "None of the members could be opened")?;
+                for (member, error) in failures {
+                    write!(f, "\n{member}: {error}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for ActivationError {}
+
+/// Opens the members of groups, which tests replace to see what would have been opened
+pub trait MemberOpener {
+    fn open(&self, member: &Member, group: &Group, config: &Config) -> Result<(), OpenError>;
+}
 
 #[derive(Default)]
-pub struct GroupService {}
+pub struct SystemOpener;
+
+impl MemberOpener for SystemOpener {
+    fn open(&self, member: &Member, group: &Group, config: &Config) -> Result<(), OpenError> {
+        member.open(&group.window_policy, config.installed_apps())
+    }
+}
+
+/// Shared by the running actions, so its state is only borrowed in between awaits
+pub struct GroupService<M: WindowModel = WindowManager, O: MemberOpener = SystemOpener> {
+    opener: O,
+    focus_service: RefCell<FocusService<M>>,
+    previous_app: RefCell<Option<App>>, // the app in front before the last activation
+    last_opened: RefCell<HashMap<Uuid, String>>,
+}
+
+impl<M: WindowModel + Default, O: MemberOpener + Default> Default for GroupService<M, O> {
+    fn default() -> Self {
+        Self {
+            opener: O::default(),
+            focus_service: RefCell::default(),
+            previous_app: RefCell::default(),
+            last_opened: RefCell::default(),
+        }
+    }
+}
+
+impl<M: WindowModel, O: MemberOpener> GroupService<M, O> {
+    pub fn open(&self, config: &Config, group_id: Uuid) -> Result<(), ActivationError> {
+        let group = &config
+            .resolved_group(group_id)
+            .ok_or(ActivationError::GroupNotFound)?;
+        let current_member = self.check_frontmost(group);
+        if current_member.is_some() && group.mode == GroupMode::Toggle {
+            self.toggle_back(group, config);
+            return Ok(());
+        }
+        // opening a url or file doesn't bring one of our apps to front, so remember where we were
+        let current_member_id = current_member.map(|m| m.id()).or_else(|| {
+            let last_opened = self.last_opened.borrow();
+            let last = group.member(last_opened.get(&group_id)?)?;
+            last.app().is_none().then(|| last.id())
+        });
+        let is_running = self.running_check();
+        let candidates = group.activation_order(current_member_id.as_deref(), is_running);
+        if candidates.is_empty() {
+            return Err(ActivationError::EmptyGroup);
+        }
+        let mut failures = Vec::new();
+        for member in candidates {
+            match self.opener.open(member, group, config) {
+                Ok(()) => {
+                    self.opened(group, member);
+                    return Ok(());
+                }
+                // a missing app or window is expected with optional members, the OS refusing isn't
+                Err(e) if e.is_missing() => {
+                    info!("Skipping {member}: {e}");
+                    failures.push((member.clone(), e));
+                }
+                Err(e) => {
+                    warn!("Could not open {member}, trying next: {e}");
+                    failures.push((member.clone(), e));
+                }
+            }
+        }
+        Err(ActivationError::AllFailed(failures))
+    }
 
-impl GroupService {
-    pub fn open(&self, config_service: &ConfigService, group_id: Uuid) {
-        let apps = config_service.group(group_id).unwrap().apps();
-        if let Some(app) = apps.iter().next() {
-            // TODO find current app and go next if in group
-            let _ = app.open();
+    pub fn open_member(
+        &self,
+        config: &Config,
+        group_id: Uuid,
+        member_id: &str,
+    ) -> Result<(), ActivationError> {
+        let group = &config
+            .resolved_group(group_id)
+            .ok_or(ActivationError::GroupNotFound)?;
+        let Some(member) = group.member(member_id) else {
+            return self.open(config, group_id);
+        };
+        self.check_frontmost(group);
+        match self.opener.open(member, group, config) {
+            Ok(()) => {
+                self.opened(group, member);
+                Ok(())
+            }
+            Err(e) => {
+                warn!("Could not open {member}, falling back to the group: {e}");
+                self.open(config, group_id)
+            }
         }
     }
+
+    pub fn frontmost_app(&self) -> Option<String> {
+        self.focus_service.borrow().frontmost_app()
+    }
+
+    pub async fn launch_all(
+        &self,
+        config: &Config,
+        group_id: Uuid,
+    ) -> Result<LaunchReport, ActivationError> {
+        let group = &config
+            .resolved_group(group_id)
+            .ok_or(ActivationError::GroupNotFound)?;
+        let mut results = Vec::new();
+        for (i, member) in group.launch_members().enumerate() {
+            if i > 0 && !group.launch_stagger.is_zero() {
+                tokio::time::sleep(group.launch_stagger).await;
+            }
+            let result = self.opener.open(member, group, config);
+            results.push((member.clone(), result));
+        }
+        if let Some(primary) = group.primary_member() {
+            // opening a url or command again would open it twice, a running app is just focused
+            let launched = results.iter().any(|(m, _)| m == primary);
+            let is_running = self.running_check();
+            if (!launched || primary.app().is_some_and(is_running))
+                && let Err(e) = self.opener.open(primary, group, config)
+            {
+                warn!("Could not bring {primary} to front: {e}");
+            }
+            self.last_opened.borrow_mut().insert(group_id, primary.id());
+        }
+        self.focus(group);
+        Ok(LaunchReport { results })
+    }
+
+    fn running_check(&self) -> impl Fn(&App) -> bool {
+        let running_apps = self.focus_service.borrow().running_apps();
+        move |app: &App| {
+            running_apps
+                .iter()
+                .any(|id| id.eq_ignore_ascii_case(&app.id()))
+        }
+    }
+
+    /// Returns the member in front if there is one, otherwise remembers the app to return to later
+    fn check_frontmost<'g>(&self, group: &'g Group) -> Option<&'g Member> {
+        let focus_service = self.focus_service.borrow();
+        let frontmost_app = focus_service.frontmost_app();
+        let title = focus_service.frontmost_title();
+        let current = frontmost_app
+            .as_deref()
+            .and_then(|app_id| group.current_member(app_id, title.as_deref()));
+        if current.is_none() {
+            // returning to our own window would only bring up the settings
+            let own_app = focus_service.own_app();
+            *self.previous_app.borrow_mut() = frontmost_app
+                .filter(|app_id| own_app.as_ref() != Some(app_id))
+                .map(App::from);
+        }
+        current
+    }
+
+    fn toggle_back(&self, group: &Group, config: &Config) {
+        // the member may be a matcher, so hide whatever app matched
+        let frontmost_app = self.focus_service.borrow().frontmost_app();
+        if let Some(app_id) = frontmost_app
+            && let Err(e) = self.focus_service.borrow_mut().hide_app(&app_id)
+        {
+            warn!("Could not hide {app_id}: {e}");
+        }
+        if let Some(previous_app) = self.previous_app.borrow().clone() {
+            let member = Member::App(AppEntry::new(previous_app));
+            if let Err(e) = self.opener.open(&member, group, config) {
+                warn!("Could not return to {member}: {e}");
+            }
+        }
+    }
+
+    pub fn restore(&self, group_id: Uuid) {
+        self.focus_service.borrow_mut().restore(group_id);
+    }
+
+    fn opened(&self, group: &Group, member: &Member) {
+        self.last_opened
+            .borrow_mut()
+            .insert(group.id(), member.id());
+        self.focus(group);
+    }
+
+    fn focus(&self, group: &Group) {
+        if !group.exclusive {
+            return;
+        }
+        let is_member = |app_id: &str| group.contains_app(app_id);
+        let hidden = self
+            .focus_service
+            .borrow_mut()
+            .hide_others(group.id(), is_member);
+        if let Err(e) = hidden {
+            warn!("Could not hide apps outside of group {group}: {e}");
+        }
+    }
+
+    #[cfg(test)]
+    pub fn opener(&self) -> &O {
+        &self.opener
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::*;
+    use crate::models::MemberFlags;
+    use crate::services::focus::tests::FakeWindowModel;
+
+    /// Records the ids of the members it was asked to open
+    #[derive(Default)]
+    pub struct FakeOpener {
+        pub opened: RefCell<Vec<String>>,
+    }
+
+    impl MemberOpener for FakeOpener {
+        fn open(&self, member: &Member, _group: &Group, _config: &Config) -> Result<(), OpenError> {
+            self.opened.borrow_mut().push(member.id());
+            Ok(())
+        }
+    }
+
+    fn setup_service(running: &[&str]) -> GroupService<FakeWindowModel, FakeOpener> {
+        with_model(FakeWindowModel {
+            windows: running.iter().map(|id| (id.to_string(), 1)).collect(),
+            ..FakeWindowModel::default()
+        })
+    }
+
+    /// The editor owns window 1 and the browser window 2, GroupCtrl itself has none
+    fn setup_toggle_service(frontmost: &str) -> GroupService<FakeWindowModel, FakeOpener> {
+        with_model(FakeWindowModel {
+            windows: vec![("editor".to_string(), 1), ("browser".to_string(), 2)],
+            frontmost: Some(frontmost.to_string()),
+            own_app: Some("groupctrl".to_string()),
+            ..FakeWindowModel::default()
+        })
+    }
+
+    fn with_model(model: FakeWindowModel) -> GroupService<FakeWindowModel, FakeOpener> {
+        GroupService {
+            opener: FakeOpener::default(),
+            focus_service: RefCell::new(FocusService::new(model)),
+            previous_app: RefCell::default(),
+            last_opened: RefCell::default(),
+        }
+    }
+
+    fn setup_group(config: &mut Config, members: Vec<Member>, primary: &str) -> Uuid {
+        let group_id = config.add_group("Work".to_string());
+        for member in members {
+            config.add_member(group_id, member);
+        }
+        config.set_primary_member(group_id, Some(primary.to_string()));
+        group_id
+    }
+
+    fn app(id: &str) -> Member {
+        Member::App(AppEntry::new(App::from(id.to_string())))
+    }
+
+    #[tokio::test]
+    async fn launch_all_opens_command_primary_once() {
+        // Arrange
+        let service = setup_service(&[]);
+        let mut config = Config::default();
+        let url = Member::Url("https://example.com".to_string());
+        let command = Member::Command("make".to_string());
+        let group_id = setup_group(&mut config, vec![url, command], "make");
+
+        // Act
+        let report = service.launch_all(&config, group_id).await.unwrap();
+
+        // Assert
+        assert_eq!(report.results.len(), 2);
+        assert_eq!(
+            *service.opener.opened.borrow(),
+            vec!["https://example.com", "make"]
+        );
+    }
+
+    #[tokio::test]
+    async fn launch_all_focuses_running_primary() {
+        // Arrange
+        let service = setup_service(&["editor"]);
+        let mut config = Config::default();
+        let group_id = setup_group(&mut config, vec![app("editor"), app("chat")], "editor");
+
+        // Act
+        service.launch_all(&config, group_id).await.unwrap();
+
+        // Assert
+        assert_eq!(
+            *service.opener.opened.borrow(),
+            vec!["editor", "chat", "editor"]
+        );
+    }
+
+    #[tokio::test]
+    async fn launch_all_in_member_order() {
+        // Arrange
+        let service = setup_service(&[]);
+        let mut config = Config::default();
+        let members = vec![app("editor"), app("chat"), app("browser")];
+        let group_id = setup_group(&mut config, members, "browser");
+        config.set_launch_stagger(group_id, Duration::ZERO);
+        let flags = MemberFlags {
+            in_launch_all: false,
+            ..MemberFlags::default()
+        };
+        config.set_member_flags(group_id, "chat", flags);
+
+        // Act
+        let report = service.launch_all(&config, group_id).await.unwrap();
+
+        // Assert
+        let launched: Vec<String> = report.results.iter().map(|(m, _)| m.id()).collect();
+        assert_eq!(launched, vec!["editor", "browser"]);
+        assert_eq!(report.into_outcome(), ActionOutcome::Success);
+        assert_eq!(*service.opener.opened.borrow(), vec!["editor", "browser"]);
+    }
+
+    #[tokio::test]
+    async fn launch_all_staggers() {
+        // Arrange
+        let service = setup_service(&[]);
+        let mut config = Config::default();
+        let members = vec![app("editor"), app("chat"), app("browser")];
+        let group_id = setup_group(&mut config, members, "editor");
+        let stagger = Duration::from_millis(20);
+        config.set_launch_stagger(group_id, stagger);
+        let start = Instant::now();
+
+        // Act
+        service.launch_all(&config, group_id).await.unwrap();
+
+        // Assert
+        assert!(start.elapsed() >= stagger * 2);
+    }
+
+    #[tokio::test]
+    async fn removed_group_is_not_found() {
+        // Arrange
+        let service = setup_service(&["editor"]);
+        let mut config = Config::default();
+        let group_id = setup_group(&mut config, vec![app("editor")], "editor");
+        config.remove_group(group_id);
+
+        // Act
+        let opened = service.open(&config, group_id);
+        let opened_member = service.open_member(&config, group_id, "editor");
+        let launched = service.launch_all(&config, group_id).await;
+
+        // Assert
+        assert!(matches!(opened, Err(ActivationError::GroupNotFound)));
+        assert!(matches!(opened_member, Err(ActivationError::GroupNotFound)));
+        assert!(matches!(launched, Err(ActivationError::GroupNotFound)));
+        assert!(service.opener.opened.borrow().is_empty());
+    }
+
+    fn setup_toggle_group(config: &mut Config) -> Uuid {
+        let group_id = setup_group(config, vec![app("editor")], "editor");
+        config.set_mode(group_id, GroupMode::Toggle);
+        group_id
+    }
+
+    #[test]
+    fn open_remembers_app_in_front() {
+        // Arrange
+        let service = setup_toggle_service("browser");
+        let mut config = Config::default();
+        let group_id = setup_toggle_group(&mut config);
+
+        // Act
+        service.open(&config, group_id).unwrap();
+
+        // Assert
+        assert_eq!(*service.opener.opened.borrow(), vec!["editor"]);
+        assert_eq!(
+            *service.previous_app.borrow(),
+            Some(App::from("browser".to_string()))
+        );
+    }
+
+    #[test]
+    fn open_never_remembers_own_app() {
+        // Arrange
+        let service = setup_toggle_service("groupctrl");
+        *service.previous_app.borrow_mut() = Some(App::from("browser".to_string()));
+        let mut config = Config::default();
+        let group_id = setup_toggle_group(&mut config);
+
+        // Act
+        service.open(&config, group_id).unwrap();
+
+        // Assert
+        assert_eq!(*service.opener.opened.borrow(), vec!["editor"]);
+        assert_eq!(*service.previous_app.borrow(), None);
+    }
+
+    #[test]
+    fn toggle_hides_member_and_returns() {
+        // Arrange
+        let service = setup_toggle_service("editor");
+        *service.previous_app.borrow_mut() = Some(App::from("browser".to_string()));
+        let mut config = Config::default();
+        let group_id = setup_toggle_group(&mut config);
+
+        // Act
+        service.open(&config, group_id).unwrap();
+
+        // Assert
+        assert_eq!(service.focus_service.borrow().model().hidden, vec![1]);
+        assert_eq!(*service.opener.opened.borrow(), vec!["browser"]);
+    }
+
+    #[test]
+    fn toggle_without_previous_app_only_hides() {
+        // Arrange
+        let service = setup_toggle_service("editor");
+        let mut config = Config::default();
+        let group_id = setup_toggle_group(&mut config);
+
+        // Act
+        service.open(&config, group_id).unwrap();
+
+        // Assert
+        assert_eq!(service.focus_service.borrow().model().hidden, vec![1]);
+        assert!(service.opener.opened.borrow().is_empty());
+    }
 }
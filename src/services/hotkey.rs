@@ -1,6 +1,9 @@
 mod binder;
+mod chord;
 mod sender;
 mod service;
+mod trigger;
 
+pub use binder::BindError;
 pub use sender::SharedSender;
-pub use service::HotkeyService;
+pub use service::{HotkeyError, HotkeyService};
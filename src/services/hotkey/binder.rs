@@ -1,14 +1,36 @@
 use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
 
-use anyhow::anyhow;
-use dioxus::desktop::{ShortcutHandle, window};
+use dioxus::desktop::{ShortcutHandle, ShortcutRegistryError, window};
 use global_hotkey::HotKeyState::Pressed;
+use log::warn;
 
 use super::sender::SharedSender;
 use crate::models::{Action, Hotkey};
 
+/// Why the OS did not register a hotkey
+#[derive(Debug, Clone, PartialEq)]
+pub enum BindError {
+    /// Registered by another application
+    Taken(Hotkey),
+    Invalid(Hotkey),
+    Failed(String),
+}
+
+impl Display for BindError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BindError::Taken(hotkey) => write!(f, "{hotkey} is already used by another app"),
+            BindError::Invalid(hotkey) => write!(f, "{hotkey} can't be used as a hotkey"),
+            BindError::Failed(reason) => write!(f, "the OS refused it ({reason})"),
+        }
+    }
+}
+
+impl std::error::Error for BindError {}
+
 pub trait HotkeyBinder {
-    fn bind_hotkey(&mut self, hotkey: Hotkey, action: &Action) -> anyhow::Result<()>;
+    fn bind_hotkey(&mut self, hotkey: Hotkey, action: &Action) -> Result<(), BindError>;
     fn unbind_hotkey(&mut self, hotkey: Hotkey);
 }
 
@@ -32,33 +54,51 @@ impl DioxusBinder {
 }
 
 impl HotkeyBinder for DioxusBinder {
-    fn bind_hotkey(&mut self, hotkey: Hotkey, action: &Action) -> anyhow::Result<()> {
+    fn bind_hotkey(&mut self, hotkey: Hotkey, action: &Action) -> Result<(), BindError> {
         let my_recorded_register_sender = self.record_registered_sender.clone();
         let my_action_sender = self.action_sender.clone();
         let my_action = action.clone();
         let callback = move |state| {
-            if state == Pressed {
-                if let Some(sender) = my_recorded_register_sender.get() {
-                    let _ = sender.unbounded_send(hotkey);
-                } else {
-                    let _ = my_action_sender
-                        .get()
-                        .unwrap()
-                        .unbounded_send(my_action.clone());
-                }
+            if state == Pressed
+                && let Some(sender) = my_recorded_register_sender.get()
+            {
+                let _ = sender.unbounded_send(hotkey);
+                return;
             }
+            let action = match &my_action {
+                // releases tell taps from holds
+                Action::TriggerKey { hotkey, .. } => Action::TriggerKey {
+                    hotkey: *hotkey,
+                    pressed: state == Pressed,
+                },
+                action if state == Pressed => action.clone(),
+                _ => return,
+            };
+            let _ = my_action_sender.get().unwrap().unbounded_send(action);
         };
         let handle = window()
             .create_shortcut(hotkey.0, callback)
-            // manual error mapping because this error doesn't implement Display
-            .map_err(|e| anyhow!("Failed to create shortcut: {:?}", e))?;
+            .map_err(|e| bind_error(hotkey, e))?;
         self.handles.insert(hotkey, handle);
         Ok(())
     }
 
     fn unbind_hotkey(&mut self, hotkey: Hotkey) {
-        let handle = self.handles.remove(&hotkey).unwrap();
-        window().remove_shortcut(handle);
+        match self.handles.remove(&hotkey) {
+            Some(handle) => window().remove_shortcut(handle),
+            None => warn!("{hotkey} was not registered, nothing to unbind"),
+        }
+    }
+}
+
+// manual error mapping because this error doesn't implement Display
+fn bind_error(hotkey: Hotkey, error: ShortcutRegistryError) -> BindError {
+    match error {
+        ShortcutRegistryError::InvalidShortcut(_) => BindError::Invalid(hotkey),
+        ShortcutRegistryError::Other(e) => match e.downcast_ref::<global_hotkey::Error>() {
+            Some(global_hotkey::Error::AlreadyRegistered(_)) => BindError::Taken(hotkey),
+            _ => BindError::Failed(format!("{e:?}")),
+        },
     }
 }
 
@@ -76,10 +116,15 @@ pub mod tests {
 
     pub struct MockBinder {
         pub events: Arc<Mutex<Vec<MockEvent>>>,
+        /// Registered by another application
+        pub taken: Vec<Hotkey>,
     }
 
     impl HotkeyBinder for MockBinder {
-        fn bind_hotkey(&mut self, hotkey: Hotkey, action: &Action) -> anyhow::Result<()> {
+        fn bind_hotkey(&mut self, hotkey: Hotkey, action: &Action) -> Result<(), BindError> {
+            if self.taken.contains(&hotkey) {
+                return Err(BindError::Taken(hotkey));
+            }
             let mut events = self.events.lock().unwrap();
             events.push(MockEvent::Register(hotkey, action.clone()));
             Ok(())
@@ -0,0 +1,162 @@
+use std::collections::HashMap;
+
+use log::warn;
+
+use crate::models::{Action, Chord, Hotkey};
+
+/// What the keys pressed so far lead to
+#[derive(Debug, PartialEq)]
+pub enum ChordMatch<'t> {
+    Complete(&'t Action),
+    /// More keys are needed, one of these
+    Prefix(Vec<Hotkey>),
+    NoMatch,
+}
+
+#[derive(Default)]
+struct Node {
+    action: Option<Action>,
+    children: HashMap<Hotkey, Node>,
+}
+
+impl Node {
+    fn first_action(&self) -> Option<&Action> {
+        self.action
+            .as_ref()
+            .or_else(|| self.children.values().find_map(|c| c.first_action()))
+    }
+}
+
+/// The chords by key, to match them while they are typed
+#[derive(Default)]
+pub struct ChordTrie {
+    root: Node,
+}
+
+impl ChordTrie {
+    pub fn new(chords: impl IntoIterator<Item = (Chord, Action)>) -> Self {
+        let mut trie = Self::default();
+        for (chord, action) in chords {
+            if let Err(conflict) = trie.insert(&chord, action) {
+                warn!("Chord {chord} conflicts with {conflict}, ignoring it");
+            }
+        }
+        trie
+    }
+
+    /// Fails with the action of a chord which is a prefix of this one or the other way round,
+    /// as there would be no telling whether to wait for more keys
+    pub fn insert(&mut self, chord: &Chord, action: Action) -> Result<(), Action> {
+        if let Some(conflict) = self.conflict(chord) {
+            return Err(conflict.clone());
+        }
+        let mut node = &mut self.root;
+        for key in chord.keys() {
+            node = node.children.entry(*key).or_default();
+        }
+        node.action = Some(action);
+        Ok(())
+    }
+
+    fn conflict(&self, chord: &Chord) -> Option<&Action> {
+        let mut node = &self.root;
+        for key in chord.keys() {
+            if node.action.is_some() {
+                return node.action.as_ref();
+            }
+            node = node.children.get(key)?;
+        }
+        node.first_action()
+    }
+
+    pub fn lookup(&self, keys: &[Hotkey]) -> ChordMatch<'_> {
+        let mut node = &self.root;
+        for key in keys {
+            match node.children.get(key) {
+                Some(child) => node = child,
+                None => return ChordMatch::NoMatch,
+            }
+        }
+        match &node.action {
+            Some(action) => ChordMatch::Complete(action),
+            None if node.children.is_empty() => ChordMatch::NoMatch,
+            None => ChordMatch::Prefix(node.children.keys().copied().collect()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use global_hotkey::hotkey::{Code, Modifiers};
+
+    use super::*;
+
+    fn leader() -> Hotkey {
+        Hotkey::new(Modifiers::CONTROL, Code::Space)
+    }
+
+    fn key(code: Code) -> Hotkey {
+        Hotkey::new(Modifiers::empty(), code)
+    }
+
+    fn chord(codes: &[Code]) -> Chord {
+        let keys = std::iter::once(leader()).chain(codes.iter().map(|c| key(*c)));
+        Chord::new(keys.collect()).unwrap()
+    }
+
+    #[test]
+    fn lookup_follows_the_keys() {
+        // Arrange
+        let trie = ChordTrie::new([
+            (chord(&[Code::KeyB]), Action::Mock("browsers")),
+            (chord(&[Code::KeyC]), Action::Mock("chat")),
+        ]);
+
+        // Act
+        let after_leader = trie.lookup(&[leader()]);
+        let complete = trie.lookup(&[leader(), key(Code::KeyC)]);
+        let unknown = trie.lookup(&[leader(), key(Code::KeyX)]);
+
+        // Assert
+        let ChordMatch::Prefix(mut next) = after_leader else {
+            panic!("expected a prefix, got {after_leader:?}");
+        };
+        next.sort_by_key(|k| k.to_string());
+        assert_eq!(next, vec![key(Code::KeyB), key(Code::KeyC)]);
+        assert_eq!(complete, ChordMatch::Complete(&Action::Mock("chat")));
+        assert_eq!(unknown, ChordMatch::NoMatch);
+    }
+
+    #[test]
+    fn insert_rejects_prefixes() {
+        // Arrange
+        let mut trie = ChordTrie::new([(chord(&[Code::KeyB]), Action::Mock("browsers"))]);
+
+        // Act
+        let longer = trie.insert(&chord(&[Code::KeyB, Code::KeyF]), Action::Mock("firefox"));
+        let same = trie.insert(&chord(&[Code::KeyB]), Action::Mock("again"));
+        let sibling = trie.insert(&chord(&[Code::KeyC]), Action::Mock("chat"));
+
+        // Assert
+        assert_eq!(longer, Err(Action::Mock("browsers")));
+        assert_eq!(same, Err(Action::Mock("browsers")));
+        assert_eq!(sibling, Ok(()));
+    }
+
+    #[test]
+    fn insert_rejects_chords_that_others_extend() {
+        // Arrange
+        let mut trie =
+            ChordTrie::new([(chord(&[Code::KeyB, Code::KeyF]), Action::Mock("firefox"))]);
+
+        // Act
+        let shorter = trie.insert(&chord(&[Code::KeyB]), Action::Mock("browsers"));
+
+        // Assert
+        assert_eq!(shorter, Err(Action::Mock("firefox")));
+        assert_eq!(
+            trie.lookup(&[leader(), key(Code::KeyB)]),
+            ChordMatch::Prefix(vec![key(Code::KeyF)])
+        );
+    }
+}
@@ -1,9 +1,53 @@
-use crate::models::{Action, Config, Hotkey};
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+use std::time::{Duration, Instant};
+
+use log::{debug, warn};
+
+use crate::models::{Action, Chord, Config, Hotkey, HotkeyContext, Trigger};
 use crate::services::SharedSender;
-use crate::services::hotkey::binder::{DioxusBinder, HotkeyBinder};
+use crate::services::hotkey::binder::{BindError, DioxusBinder, HotkeyBinder};
+use crate::services::hotkey::chord::{ChordMatch, ChordTrie};
+use crate::services::hotkey::trigger::TriggerMachine;
+
+const CHORD_TIMEOUT: Duration = Duration::from_millis(1500);
+
+/// The keys of the chord typed so far, and the follow-up keys registered meanwhile
+#[derive(Default)]
+struct PendingChord {
+    keys: Vec<Hotkey>,
+    captured: Vec<Hotkey>,
+    since: Option<Instant>,
+}
+
+/// A bound hotkey along with how it has to be pressed to run the action
+type Triggered = (Hotkey, Trigger, Action);
+
+#[derive(Debug, PartialEq)]
+pub enum HotkeyError {
+    /// Used by another action, nothing was changed
+    Conflict(Action),
+    /// Kept in the config, but the OS did not register it
+    Failed(BindError),
+}
+
+impl Display for HotkeyError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HotkeyError::Conflict(action) => write!(f, "Already used by {action}"),
+            HotkeyError::Failed(e) => write!(f, "Could not register: {e}"),
+        }
+    }
+}
 
 pub struct HotkeyService<B: HotkeyBinder = DioxusBinder> {
     binder: B,
+    pending: PendingChord,
+    triggers: TriggerMachine,
+    /// Hotkeys which should be registered but aren't, so they must not be unbound either
+    failed: HashMap<Hotkey, BindError>,
+    /// The app the hotkeys are registered for, those inactive in front of it are unbound
+    frontmost: Option<String>,
 }
 
 impl HotkeyService<DioxusBinder> {
@@ -13,16 +57,33 @@ impl HotkeyService<DioxusBinder> {
     ) -> Self {
         Self {
             binder: DioxusBinder::new(record_registered_sender, action_sender),
+            pending: PendingChord::default(),
+            triggers: TriggerMachine::default(),
+            failed: HashMap::new(),
+            frontmost: None,
         }
     }
 }
 
 impl<B: HotkeyBinder> HotkeyService<B> {
-    fn find_conflict(config: &Config, hotkey: Option<Hotkey>) -> Option<Action> {
-        config
-            .bindings()
+    fn find_conflict(
+        config: &Config,
+        hotkey: Option<Hotkey>,
+        trigger: Trigger,
+        action: &Action,
+    ) -> Option<Action> {
+        let hotkey = hotkey?; // unbound actions never conflict
+        triggered(config)
             .into_iter()
-            .find_map(|(hk, a)| (hk == hotkey).then_some(a))
+            .find_map(|(hk, t, a)| (hk == hotkey && t == trigger && a != *action).then_some(a))
+            .or_else(|| {
+                // a follow-up key would run the plain hotkey's action instead while a chord
+                // is pending
+                config
+                    .chords()
+                    .into_iter()
+                    .find_map(|(chord, a)| chord.keys().contains(&hotkey).then_some(a))
+            })
     }
 
     pub fn bind_hotkey(
@@ -31,22 +92,402 @@ impl<B: HotkeyBinder> HotkeyService<B> {
         hotkey: Option<Hotkey>,
         existing_hotkey: Option<Hotkey>,
         action: Action,
-    ) -> Option<Action> {
+    ) -> Result<(), HotkeyError> {
         if hotkey == existing_hotkey {
-            return None;
+            return Ok(());
+        }
+        let trigger = config.trigger(&action);
+        if let Some(conflict) = Self::find_conflict(config, hotkey, trigger, &action) {
+            return Err(HotkeyError::Conflict(conflict));
         }
-        if let Some(conflict) = Self::find_conflict(config, hotkey) {
-            return Some(conflict);
+        let before = registered(config, self.frontmost());
+        let mut after = before.clone();
+        after.retain(|(_, _, a)| *a != action);
+        if let Some(hk) = hotkey
+            && active(config, &action, self.frontmost())
+        {
+            after.push((hk, trigger, action));
         }
+        let hotkeys = existing_hotkey.into_iter().chain(hotkey).collect();
+        self.reregister(config, &before, &after, hotkeys);
+        self.check_registered(hotkey)
+    }
 
-        if let Some(ex_hk) = existing_hotkey {
-            self.binder.unbind_hotkey(ex_hk);
+    /// Changes how the action's hotkey has to be pressed, which may share the hotkey with others
+    pub fn bind_trigger(
+        &mut self,
+        config: &Config,
+        action: Action,
+        trigger: Trigger,
+    ) -> Result<(), HotkeyError> {
+        let hotkey = config.get_hotkey(&action);
+        if let Some(conflict) = Self::find_conflict(config, hotkey, trigger, &action) {
+            return Err(HotkeyError::Conflict(conflict));
         }
-        if let Some(hk) = hotkey {
-            self.binder.bind_hotkey(hk, &action).unwrap() // TODO handle error
+        let before = registered(config, self.frontmost());
+        let after: Vec<Triggered> = before
+            .iter()
+            .cloned()
+            .map(|(hk, t, a)| {
+                if a == action {
+                    (hk, trigger, a)
+                } else {
+                    (hk, t, a)
+                }
+            })
+            .collect();
+        self.reregister(config, &before, &after, hotkey.into_iter().collect());
+        self.check_registered(hotkey)
+    }
+
+    fn check_registered(&self, hotkey: Option<Hotkey>) -> Result<(), HotkeyError> {
+        match hotkey.and_then(|hk| self.failed.get(&hk)) {
+            Some(e) => Err(HotkeyError::Failed(e.clone())),
+            None => Ok(()),
         }
+    }
+
+    /// Why the hotkey could not be registered, if it is bound but not registered
+    pub fn failure(&self, hotkey: Hotkey) -> Option<&BindError> {
+        self.failed.get(&hotkey)
+    }
+
+    fn reregister(
+        &mut self,
+        config: &Config,
+        before: &[Triggered],
+        after: &[Triggered],
+        hotkeys: Vec<Hotkey>,
+    ) {
+        // while paused the others are registered from the config on resume
+        let paused = config.settings().hotkeys_paused;
+        let live = |bound: &[Triggered], hk| {
+            let registered = !paused || toggles_pause(bound, hk);
+            registered.then(|| registration(bound, hk)).flatten()
+        };
+        let changes: Vec<(Hotkey, Option<Action>, Option<Action>)> = hotkeys
+            .into_iter()
+            .map(|hk| (hk, live(before, hk), live(after, hk)))
+            .filter(|(_, old, new)| old != new)
+            .collect();
+        for (hk, old, _) in &changes {
+            if old.is_some() {
+                self.unbind(*hk);
+            }
+        }
+        for (hk, _, new) in changes {
+            if let Some(action) = new {
+                self.bind(hk, &action);
+            }
+        }
+    }
+
+    /// A failure is remembered rather than returned, so registering the others goes on
+    fn bind(&mut self, hotkey: Hotkey, action: &Action) {
+        if let Err(e) = self.binder.bind_hotkey(hotkey, action) {
+            warn!("Could not register {hotkey} for {action}: {e}");
+            self.failed.insert(hotkey, e);
+        }
+    }
+
+    fn unbind(&mut self, hotkey: Hotkey) {
+        if self.failed.remove(&hotkey).is_none() {
+            self.binder.unbind_hotkey(hotkey);
+        }
+    }
+
+    /// Turns a key event sent by the hotkeys into the action to run, if it completes a chord
+    /// or trigger. Other actions are passed through.
+    pub fn resolve(&mut self, config: &Config, action: Action) -> Option<Action> {
+        match action {
+            Action::ChordKey { hotkey } => self.press_chord_key(config, hotkey),
+            Action::TriggerKey { hotkey, pressed } => {
+                let now = Instant::now();
+                let trigger = match pressed {
+                    true => self
+                        .triggers
+                        .press(hotkey, self.bound_triggers(config, hotkey), now),
+                    false => self.triggers.release(hotkey, now),
+                };
+                let action = self.triggered_action(config, hotkey, trigger?)?;
+                // the pause hotkey may share its hotkey with others, which are paused too
+                let paused = config.settings().hotkeys_paused;
+                (!paused || action == Action::ToggleHotkeysPaused).then_some(action)
+            }
+            action => Some(action),
+        }
+    }
+
+    /// Unbinds every hotkey but the pause hotkey, or registers them again from the config
+    pub fn set_paused(&mut self, config: &Config, paused: bool) {
+        if config.settings().hotkeys_paused == paused {
+            return;
+        }
+        self.cancel_chord();
+        let bound = registered(config, self.frontmost());
+        let hotkeys: HashSet<Hotkey> = bound.iter().map(|(hk, _, _)| *hk).collect();
+        let registrations = hotkeys
+            .into_iter()
+            .filter(|hk| !toggles_pause(&bound, *hk))
+            .filter_map(|hk| Some((hk, registration(&bound, hk)?)));
+        let chord_keys = leaders(enabled_chords(config).iter().map(|(c, _)| c))
+            .into_iter()
+            .map(|leader| (leader, Action::ChordKey { hotkey: leader }));
+        for (hotkey, action) in registrations.chain(chord_keys) {
+            match paused {
+                true => self.unbind(hotkey),
+                false => self.bind(hotkey, &action),
+            }
+        }
+    }
+
+    /// Registers the hotkeys and chords of the actions again, or unbinds them while keeping
+    /// them reserved
+    pub fn set_enabled(&mut self, config: &Config, actions: &[Action], enabled: bool) {
+        let is_enabled = |a: &Action| match actions.contains(a) {
+            true => enabled,
+            false => config.is_enabled(a),
+        };
+        let before = registered(config, self.frontmost());
+        let allows = |a: &Action| config.context(a).allows(self.frontmost());
+        let after: Vec<Triggered> = triggered(config)
+            .into_iter()
+            .filter(|(_, _, a)| is_enabled(a) && allows(a))
+            .collect();
+        let hotkeys: HashSet<Hotkey> = triggered(config)
+            .into_iter()
+            .filter_map(|(hk, _, a)| actions.contains(&a).then_some(hk))
+            .collect();
+        self.reregister(config, &before, &after, hotkeys.into_iter().collect());
+
+        let chords = config.chords();
+        let before_leaders = leaders(enabled_chords(config).iter().map(|(c, _)| c));
+        let after_leaders = leaders(chords.iter().filter(|(_, a)| is_enabled(a)).map(|(c, _)| c));
+        self.swap_leaders(config, &before_leaders, &after_leaders);
+    }
+
+    /// When a pending chord or trigger has to be looked at again
+    pub fn deadline(&self) -> Option<Instant> {
+        let chord_deadline = self.pending.since.map(|since| since + CHORD_TIMEOUT);
+        chord_deadline
+            .into_iter()
+            .chain(self.triggers.deadline())
+            .min()
+    }
+
+    /// Cancels a chord which timed out, and returns the actions of triggers whose time is up
+    pub fn expire(&mut self, config: &Config) -> Vec<Action> {
+        let now = Instant::now();
+        if self
+            .pending
+            .since
+            .is_some_and(|since| since + CHORD_TIMEOUT <= now)
+        {
+            debug!("Chord timed out");
+            self.cancel_chord();
+        }
+        self.triggers
+            .tick(now)
+            .into_iter()
+            .filter_map(|(hotkey, trigger)| self.triggered_action(config, hotkey, trigger))
+            .collect()
+    }
+
+    /// Registers the leader of the chord, unless another chord shares it already.
+    /// Returns the conflicting action instead if the chord can't be told apart from another one.
+    pub fn bind_chord(
+        &mut self,
+        config: &Config,
+        chord: Option<Chord>,
+        existing_chord: Option<Chord>,
+        action: Action,
+    ) -> Option<Action> {
+        if chord == existing_chord {
+            return None;
+        }
+        let others: Vec<(Chord, Action)> = config
+            .chords()
+            .into_iter()
+            .filter(|(_, a)| *a != action)
+            .collect();
+        if let Some(chord) = &chord {
+            // leaders are shared between chords, but no key of a chord with plain hotkeys
+            let plain = triggered(config)
+                .into_iter()
+                .find_map(|(hk, _, a)| chord.keys().contains(&hk).then_some(a));
+            if plain.is_some() {
+                return plain;
+            }
+            if let Err(conflict) = ChordTrie::new(others.clone()).insert(chord, action.clone()) {
+                return Some(conflict);
+            }
+        }
+
+        let enabled_others = others.iter().filter(|(_, a)| config.is_enabled(a));
+        let others_leaders = leaders(enabled_others.map(|(c, _)| c));
+        let (existing_chord, chord) = match config.is_enabled(&action) {
+            true => (existing_chord, chord),
+            false => (None, None),
+        };
+        let before = &others_leaders | &leaders(existing_chord.iter());
+        let after = &others_leaders | &leaders(chord.iter());
+        self.swap_leaders(config, &before, &after);
         None
     }
+
+    fn swap_leaders(&mut self, config: &Config, before: &HashSet<Hotkey>, after: &HashSet<Hotkey>) {
+        if config.settings().hotkeys_paused {
+            return; // registered from the config on resume
+        }
+        for leader in before.difference(after) {
+            self.unbind(*leader);
+        }
+        for leader in after.difference(before) {
+            self.bind(*leader, &Action::ChordKey { hotkey: *leader });
+        }
+    }
+
+    /// Advances the pending chord, returning the action once a chord is complete
+    fn press_chord_key(&mut self, config: &Config, hotkey: Hotkey) -> Option<Action> {
+        self.release_captured();
+        self.pending.keys.push(hotkey);
+        let trie = ChordTrie::new(enabled_chords(config));
+        match trie.lookup(&self.pending.keys) {
+            ChordMatch::Complete(action) => {
+                let action = action.clone();
+                self.cancel_chord();
+                Some(action)
+            }
+            ChordMatch::Prefix(next_keys) => {
+                // the follow-up keys are only ours while the chord is pending
+                for key in next_keys {
+                    let chord_key = Action::ChordKey { hotkey: key };
+                    match self.binder.bind_hotkey(key, &chord_key) {
+                        Ok(()) => self.pending.captured.push(key),
+                        Err(e) => warn!("Not capturing {key} for the chord: {e}"),
+                    }
+                }
+                self.pending.since = Some(Instant::now());
+                None
+            }
+            ChordMatch::NoMatch => {
+                self.cancel_chord();
+                None
+            }
+        }
+    }
+
+    pub fn frontmost(&self) -> Option<&str> {
+        self.frontmost.as_deref()
+    }
+
+    /// Unbinds the hotkeys inactive in front of the app, and binds the ones active again
+    pub fn set_frontmost(&mut self, config: &Config, frontmost: Option<String>) {
+        if self.frontmost == frontmost {
+            return;
+        }
+        let before = registered(config, self.frontmost());
+        self.frontmost = frontmost;
+        let after = registered(config, self.frontmost());
+        let hotkeys: HashSet<Hotkey> = before.iter().chain(&after).map(|(hk, _, _)| *hk).collect();
+        self.reregister(config, &before, &after, hotkeys.into_iter().collect());
+    }
+
+    /// Registers the action's hotkey only in front of the apps the context allows
+    pub fn bind_context(&mut self, config: &Config, action: &Action, context: &HotkeyContext) {
+        let hotkey = config.get_hotkey(action);
+        let before = registered(config, self.frontmost());
+        let mut after = before.clone();
+        after.retain(|(_, _, a)| a != action);
+        if let Some(hk) = hotkey
+            && config.is_enabled(action)
+            && context.allows(self.frontmost())
+        {
+            after.push((hk, config.trigger(action), action.clone()));
+        }
+        self.reregister(config, &before, &after, hotkey.into_iter().collect());
+    }
+
+    fn bound_triggers(&self, config: &Config, hotkey: Hotkey) -> Vec<Trigger> {
+        registered(config, self.frontmost())
+            .into_iter()
+            .filter_map(|(hk, t, _)| (hk == hotkey).then_some(t))
+            .collect()
+    }
+
+    fn triggered_action(
+        &self,
+        config: &Config,
+        hotkey: Hotkey,
+        trigger: Trigger,
+    ) -> Option<Action> {
+        registered(config, self.frontmost())
+            .into_iter()
+            .find_map(|(hk, t, a)| (hk == hotkey && t == trigger).then_some(a))
+    }
+
+    fn cancel_chord(&mut self) {
+        self.release_captured();
+        self.pending = PendingChord::default();
+    }
+
+    fn release_captured(&mut self) {
+        for key in self.pending.captured.drain(..) {
+            self.binder.unbind_hotkey(key);
+        }
+    }
+}
+
+fn triggered(config: &Config) -> Vec<Triggered> {
+    config
+        .bindings()
+        .into_iter()
+        .filter_map(|(s, a)| Some((s.hotkey?, s.trigger, a)))
+        .collect()
+}
+
+/// The bound hotkeys of enabled groups and bindings which are active in front of the app,
+/// the others are only reserved
+fn registered(config: &Config, frontmost: Option<&str>) -> Vec<Triggered> {
+    triggered(config)
+        .into_iter()
+        .filter(|(_, _, a)| active(config, a, frontmost))
+        .collect()
+}
+
+fn active(config: &Config, action: &Action, frontmost: Option<&str>) -> bool {
+    config.is_enabled(action) && config.context(action).allows(frontmost)
+}
+
+fn enabled_chords(config: &Config) -> Vec<(Chord, Action)> {
+    config
+        .chords()
+        .into_iter()
+        .filter(|(_, a)| config.is_enabled(a))
+        .collect()
+}
+
+/// The action to register for the hotkey, or its key events if they need telling apart
+fn registration(bound: &[Triggered], hotkey: Hotkey) -> Option<Action> {
+    let mut actions = bound.iter().filter(|(hk, _, _)| *hk == hotkey);
+    match (actions.next(), actions.next()) {
+        (None, _) => None,
+        (Some((_, Trigger::Tap, action)), None) => Some(action.clone()),
+        _ => Some(Action::TriggerKey {
+            hotkey,
+            pressed: true,
+        }),
+    }
+}
+
+fn toggles_pause(bound: &[Triggered], hotkey: Hotkey) -> bool {
+    bound
+        .iter()
+        .any(|(hk, _, a)| *hk == hotkey && *a == Action::ToggleHotkeysPaused)
+}
+
+fn leaders<'c>(chords: impl Iterator<Item = &'c Chord>) -> HashSet<Hotkey> {
+    chords.map(|c| c.leader()).collect()
 }
 
 #[cfg(test)]
@@ -62,7 +503,13 @@ mod tests {
 
     impl HotkeyService<MockBinder> {
         fn new_mock(binder: MockBinder) -> Self {
-            Self { binder }
+            Self {
+                binder,
+                pending: PendingChord::default(),
+                triggers: TriggerMachine::default(),
+                failed: HashMap::new(),
+                frontmost: None,
+            }
         }
     }
 
@@ -70,6 +517,7 @@ mod tests {
         let events = Arc::new(Mutex::new(Vec::new()));
         let binder = MockBinder {
             events: events.clone(),
+            taken: Vec::new(),
         };
         let service = HotkeyService::new_mock(binder);
         (service, events)
@@ -77,8 +525,9 @@ mod tests {
 
     fn setup_group(config: &mut Config, hotkey: Option<Hotkey>) -> Action {
         let group_id = config.add_group("Test".to_string());
-        config.set_hotkey(group_id, hotkey);
-        Action::OpenGroup { group_id }
+        let action = Action::OpenGroup { group_id };
+        config.set_hotkey(&action, hotkey).unwrap();
+        action
     }
 
     #[test]
@@ -93,7 +542,7 @@ mod tests {
         let result = service.bind_hotkey(&config, Some(hotkey), None, action.clone());
 
         // Assert
-        assert_eq!(result, None);
+        assert_eq!(result, Ok(()));
         assert_eq!(*events.lock().unwrap(), vec![Register(hotkey, action)]);
     }
 
@@ -108,7 +557,7 @@ mod tests {
         let result = service.bind_hotkey(&config, None, None, action.clone());
 
         // Assert
-        assert_eq!(result, None);
+        assert_eq!(result, Ok(()));
         assert_eq!(*events.lock().unwrap(), vec![]);
     }
 
@@ -124,7 +573,7 @@ mod tests {
         let result = service.bind_hotkey(&config, Some(hotkey), Some(hotkey), action.clone());
 
         // Assert
-        assert_eq!(result, None);
+        assert_eq!(result, Ok(()));
         assert_eq!(*events.lock().unwrap(), vec![]);
     }
 
@@ -142,13 +591,30 @@ mod tests {
             service.bind_hotkey(&config, Some(new_hotkey), Some(old_hotkey), action.clone());
 
         // Assert
-        assert_eq!(result, None);
+        assert_eq!(result, Ok(()));
         assert_eq!(
             *events.lock().unwrap(),
             vec![Unregister(old_hotkey), Register(new_hotkey, action)]
         );
     }
 
+    #[test]
+    fn bind_hotkey_clear() {
+        // Arrange
+        let (mut service, events) = setup_service();
+        let hotkey = Hotkey::new(Modifiers::SUPER | Modifiers::SHIFT, Code::KeyF);
+        let mut config = Config::default();
+        setup_group(&mut config, None);
+        let action = setup_group(&mut config, Some(hotkey));
+
+        // Act
+        let result = service.bind_hotkey(&config, None, Some(hotkey), action);
+
+        // Assert
+        assert_eq!(result, Ok(()));
+        assert_eq!(*events.lock().unwrap(), vec![Unregister(hotkey)]);
+    }
+
     #[test]
     fn bind_hotkey_conflict() {
         // Arrange
@@ -162,7 +628,481 @@ mod tests {
         let result = service.bind_hotkey(&config, Some(hotkey), None, new_action);
 
         // Assert
-        assert_eq!(result, Some(old_action.clone()));
+        assert_eq!(result, Err(HotkeyError::Conflict(old_action.clone())));
         assert_eq!(*events.lock().unwrap(), vec![]);
     }
+
+    #[test]
+    fn bind_hotkey_conflicts_with_binding() {
+        // Arrange
+        let (mut service, events) = setup_service();
+        let hotkey = Hotkey::new(Modifiers::SUPER | Modifiers::SHIFT, Code::KeyF);
+        let mut config = Config::default();
+        let binding_id = config.add_binding("Docs".to_string());
+        let binding_action = Action::Binding { binding_id };
+        config.set_hotkey(&binding_action, Some(hotkey)).unwrap();
+        let action = setup_group(&mut config, None);
+
+        // Act
+        let result = service.bind_hotkey(&config, Some(hotkey), None, action);
+
+        // Assert
+        assert_eq!(result, Err(HotkeyError::Conflict(binding_action)));
+        assert_eq!(*events.lock().unwrap(), vec![]);
+    }
+
+    fn chord(keys: &[Hotkey]) -> Chord {
+        Chord::new(keys.to_vec()).unwrap()
+    }
+
+    #[test]
+    fn bind_chord_shares_leader() {
+        // Arrange
+        let (mut service, events) = setup_service();
+        let leader = Hotkey::new(Modifiers::CONTROL, Code::Space);
+        let browsers = chord(&[leader, Hotkey::new(Modifiers::empty(), Code::KeyB)]);
+        let chat = chord(&[leader, Hotkey::new(Modifiers::empty(), Code::KeyC)]);
+        let mut config = Config::default();
+        let first = config.add_binding("Browsers".to_string());
+        let second = config.add_binding("Chat".to_string());
+
+        // Act
+        let first_action = Action::Binding { binding_id: first };
+        let first_result = service.bind_chord(&config, Some(browsers.clone()), None, first_action);
+        config
+            .set_chord(&Action::Binding { binding_id: first }, Some(browsers))
+            .unwrap();
+        let second_action = Action::Binding { binding_id: second };
+        let second_result = service.bind_chord(&config, Some(chat), None, second_action);
+
+        // Assert
+        assert_eq!(first_result, None);
+        assert_eq!(second_result, None);
+        assert_eq!(
+            *events.lock().unwrap(),
+            vec![Register(leader, Action::ChordKey { hotkey: leader })]
+        );
+    }
+
+    #[test]
+    fn bind_chord_prefix_conflict() {
+        // Arrange
+        let (mut service, events) = setup_service();
+        let leader = Hotkey::new(Modifiers::CONTROL, Code::Space);
+        let b = Hotkey::new(Modifiers::empty(), Code::KeyB);
+        let f = Hotkey::new(Modifiers::empty(), Code::KeyF);
+        let mut config = Config::default();
+        let browsers = config.add_binding("Browsers".to_string());
+        config
+            .set_chord(
+                &Action::Binding {
+                    binding_id: browsers,
+                },
+                Some(chord(&[leader, b])),
+            )
+            .unwrap();
+        let firefox = config.add_binding("Firefox".to_string());
+
+        // Act
+        let action = Action::Binding {
+            binding_id: firefox,
+        };
+        let result = service.bind_chord(&config, Some(chord(&[leader, b, f])), None, action);
+
+        // Assert
+        assert_eq!(
+            result,
+            Some(Action::Binding {
+                binding_id: browsers
+            })
+        );
+        assert_eq!(*events.lock().unwrap(), vec![]);
+    }
+
+    #[test]
+    fn bind_hotkey_conflicts_with_chord_leader() {
+        // Arrange
+        let (mut service, _) = setup_service();
+        let leader = Hotkey::new(Modifiers::CONTROL, Code::Space);
+        let mut config = Config::default();
+        let binding_id = config.add_binding("Browsers".to_string());
+        let b = Hotkey::new(Modifiers::empty(), Code::KeyB);
+        config
+            .set_chord(&Action::Binding { binding_id }, Some(chord(&[leader, b])))
+            .unwrap();
+        let action = setup_group(&mut config, None);
+
+        // Act
+        let result = service.bind_hotkey(&config, Some(leader), None, action);
+
+        // Assert
+        assert_eq!(
+            result,
+            Err(HotkeyError::Conflict(Action::Binding { binding_id }))
+        );
+    }
+
+    #[test]
+    fn press_chord_keys() {
+        // Arrange
+        let (mut service, events) = setup_service();
+        let leader = Hotkey::new(Modifiers::CONTROL, Code::Space);
+        let b = Hotkey::new(Modifiers::empty(), Code::KeyB);
+        let mut config = Config::default();
+        let binding_id = config.add_binding("Browsers".to_string());
+        config
+            .set_chord(&Action::Binding { binding_id }, Some(chord(&[leader, b])))
+            .unwrap();
+
+        // Act
+        let after_leader = service.press_chord_key(&config, leader);
+        let pending = service.deadline().is_some();
+        let after_b = service.press_chord_key(&config, b);
+
+        // Assert
+        assert_eq!(after_leader, None);
+        assert!(pending);
+        assert_eq!(after_b, Some(Action::Binding { binding_id }));
+        assert_eq!(service.deadline(), None);
+        assert_eq!(
+            *events.lock().unwrap(),
+            vec![Register(b, Action::ChordKey { hotkey: b }), Unregister(b)]
+        );
+    }
+
+    #[test]
+    fn press_unknown_chord_key_cancels() {
+        // Arrange
+        let (mut service, _) = setup_service();
+        let leader = Hotkey::new(Modifiers::CONTROL, Code::Space);
+        let b = Hotkey::new(Modifiers::empty(), Code::KeyB);
+        let mut config = Config::default();
+        let binding_id = config.add_binding("Browsers".to_string());
+        config
+            .set_chord(&Action::Binding { binding_id }, Some(chord(&[leader, b])))
+            .unwrap();
+        service.press_chord_key(&config, leader);
+
+        // Act
+        let result = service.press_chord_key(&config, leader);
+
+        // Assert
+        assert_eq!(result, None);
+        assert_eq!(service.deadline(), None);
+    }
+
+    fn setup_tap_and_double_tap(config: &mut Config, hotkey: Hotkey) -> (Action, Action) {
+        let tap = setup_group(config, Some(hotkey));
+        let Action::OpenGroup { group_id } = tap else {
+            unreachable!()
+        };
+        let double_tap = Action::LaunchGroup { group_id };
+        config.set_trigger(&double_tap, Trigger::DoubleTap).unwrap();
+        (tap, double_tap)
+    }
+
+    #[test]
+    fn bind_hotkey_other_trigger_shares_hotkey() {
+        // Arrange
+        let (mut service, events) = setup_service();
+        let hotkey = Hotkey::new(Modifiers::SUPER, Code::KeyF);
+        let mut config = Config::default();
+        let (_, double_tap) = setup_tap_and_double_tap(&mut config, hotkey);
+
+        // Act
+        let result = service.bind_hotkey(&config, Some(hotkey), None, double_tap);
+
+        // Assert
+        assert_eq!(result, Ok(()));
+        let key_events = Action::TriggerKey {
+            hotkey,
+            pressed: true,
+        };
+        assert_eq!(
+            *events.lock().unwrap(),
+            vec![Unregister(hotkey), Register(hotkey, key_events)]
+        );
+    }
+
+    #[test]
+    fn bind_trigger_conflict() {
+        // Arrange
+        let (mut service, events) = setup_service();
+        let hotkey = Hotkey::new(Modifiers::SUPER, Code::KeyF);
+        let mut config = Config::default();
+        let (tap, double_tap) = setup_tap_and_double_tap(&mut config, hotkey);
+        config.set_hotkey(&double_tap, Some(hotkey)).unwrap();
+
+        // Act
+        let result = service.bind_trigger(&config, double_tap, Trigger::Tap);
+
+        // Assert
+        assert_eq!(result, Err(HotkeyError::Conflict(tap)));
+        assert_eq!(*events.lock().unwrap(), vec![]);
+    }
+
+    #[test]
+    fn resolve_double_tap() {
+        // Arrange
+        let (mut service, _) = setup_service();
+        let hotkey = Hotkey::new(Modifiers::SUPER, Code::KeyF);
+        let mut config = Config::default();
+        let (_, double_tap) = setup_tap_and_double_tap(&mut config, hotkey);
+        config.set_hotkey(&double_tap, Some(hotkey)).unwrap();
+        let key = |pressed| Action::TriggerKey { hotkey, pressed };
+
+        // Act
+        let resolved = [
+            service.resolve(&config, key(true)),
+            service.resolve(&config, key(false)),
+            service.resolve(&config, key(true)),
+            service.resolve(&config, key(false)),
+        ];
+
+        // Assert
+        assert_eq!(resolved, [None, None, Some(double_tap), None]);
+        assert_eq!(service.deadline(), None);
+    }
+
+    #[test]
+    fn pause_and_resume() {
+        // Arrange
+        let (mut service, events) = setup_service();
+        let hotkey = Hotkey::new(Modifiers::SUPER, Code::KeyF);
+        let pause_hotkey = Hotkey::new(Modifiers::SUPER, Code::KeyP);
+        let mut config = Config::default();
+        let action = setup_group(&mut config, Some(hotkey));
+        config
+            .set_hotkey(&Action::ToggleHotkeysPaused, Some(pause_hotkey))
+            .unwrap();
+
+        // Act
+        service.set_paused(&config, true);
+        config.set_hotkeys_paused(true);
+        service.set_paused(&config, false);
+
+        // Assert
+        assert_eq!(
+            *events.lock().unwrap(),
+            vec![Unregister(hotkey), Register(hotkey, action)]
+        );
+    }
+
+    #[test]
+    fn bind_hotkey_while_paused() {
+        // Arrange
+        let (mut service, events) = setup_service();
+        let mut config = Config::default();
+        let action = setup_group(&mut config, None);
+        config.set_hotkeys_paused(true);
+        let hotkey = Hotkey::new(Modifiers::SUPER, Code::KeyF);
+        let pause_hotkey = Hotkey::new(Modifiers::SUPER, Code::KeyP);
+
+        // Act
+        let result = service.bind_hotkey(&config, Some(hotkey), None, action);
+        let pause = Action::ToggleHotkeysPaused;
+        let pause_result = service.bind_hotkey(&config, Some(pause_hotkey), None, pause.clone());
+
+        // Assert
+        assert_eq!(result, Ok(()));
+        assert_eq!(pause_result, Ok(()));
+        assert_eq!(*events.lock().unwrap(), vec![Register(pause_hotkey, pause)]);
+    }
+
+    #[test]
+    fn disable_keeps_hotkey_reserved() {
+        // Arrange
+        let (mut service, events) = setup_service();
+        let hotkey = Hotkey::new(Modifiers::SUPER, Code::KeyF);
+        let mut config = Config::default();
+        let action = setup_group(&mut config, Some(hotkey));
+        let Action::OpenGroup { group_id } = action else {
+            unreachable!()
+        };
+        let other = setup_group(&mut config, None);
+
+        // Act
+        service.set_enabled(&config, &[action.clone()], false);
+        config.set_group_enabled(group_id, false);
+        let result = service.bind_hotkey(&config, Some(hotkey), None, other);
+
+        // Assert
+        assert_eq!(result, Err(HotkeyError::Conflict(action)));
+        assert_eq!(*events.lock().unwrap(), vec![Unregister(hotkey)]);
+    }
+
+    #[test]
+    fn bind_hotkey_disabled() {
+        // Arrange
+        let (mut service, events) = setup_service();
+        let mut config = Config::default();
+        let binding_id = config.add_binding("Browsers".to_string());
+        config.set_binding_enabled(binding_id, false);
+        let hotkey = Hotkey::new(Modifiers::SUPER, Code::KeyF);
+        let action = Action::Binding { binding_id };
+
+        // Act
+        let result = service.bind_hotkey(&config, Some(hotkey), None, action.clone());
+        config.set_hotkey(&action, Some(hotkey)).unwrap();
+        service.set_enabled(&config, &[action.clone()], true);
+
+        // Assert
+        assert_eq!(result, Ok(()));
+        assert_eq!(*events.lock().unwrap(), vec![Register(hotkey, action)]);
+    }
+
+    #[test]
+    fn bind_hotkey_taken() {
+        // Arrange
+        let (mut service, events) = setup_service();
+        let hotkey = Hotkey::new(Modifiers::SUPER, Code::KeyF);
+        service.binder.taken.push(hotkey);
+        let mut config = Config::default();
+        let action = setup_group(&mut config, None);
+
+        // Act
+        let result = service.bind_hotkey(&config, Some(hotkey), None, action.clone());
+        config.set_hotkey(&action, Some(hotkey)).unwrap();
+        let cleared = service.bind_hotkey(&config, None, Some(hotkey), action);
+
+        // Assert
+        let error = BindError::Taken(hotkey);
+        assert_eq!(result, Err(HotkeyError::Failed(error)));
+        assert_eq!(cleared, Ok(()));
+        assert_eq!(service.failure(hotkey), None);
+        assert_eq!(*events.lock().unwrap(), vec![]);
+    }
+
+    #[test]
+    fn bind_chord_leader_taken() {
+        // Arrange
+        let (mut service, events) = setup_service();
+        let leader = Hotkey::new(Modifiers::CONTROL, Code::Space);
+        let b = Hotkey::new(Modifiers::empty(), Code::KeyB);
+        service.binder.taken.push(leader);
+        let mut config = Config::default();
+        let binding_id = config.add_binding("Browsers".to_string());
+        let action = Action::Binding { binding_id };
+
+        // Act
+        let conflict = service.bind_chord(&config, Some(chord(&[leader, b])), None, action);
+
+        // Assert
+        assert_eq!(conflict, None);
+        assert_eq!(service.failure(leader), Some(&BindError::Taken(leader)));
+        assert_eq!(*events.lock().unwrap(), vec![]);
+    }
+
+    #[test]
+    fn bind_hotkey_conflicts_with_chord_follow_up() {
+        // Arrange
+        let (mut service, _) = setup_service();
+        let leader = Hotkey::new(Modifiers::CONTROL, Code::Space);
+        let b = Hotkey::new(Modifiers::empty(), Code::KeyB);
+        let mut config = Config::default();
+        let binding_id = config.add_binding("Browsers".to_string());
+        config
+            .set_chord(&Action::Binding { binding_id }, Some(chord(&[leader, b])))
+            .unwrap();
+        let action = setup_group(&mut config, None);
+
+        // Act
+        let result = service.bind_hotkey(&config, Some(b), None, action);
+
+        // Assert
+        assert_eq!(
+            result,
+            Err(HotkeyError::Conflict(Action::Binding { binding_id }))
+        );
+    }
+
+    #[test]
+    fn bind_chord_follow_up_conflicts_with_hotkey() {
+        // Arrange
+        let (mut service, events) = setup_service();
+        let leader = Hotkey::new(Modifiers::CONTROL, Code::Space);
+        let b = Hotkey::new(Modifiers::empty(), Code::KeyB);
+        let mut config = Config::default();
+        let plain = setup_group(&mut config, Some(b));
+        let binding_id = config.add_binding("Browsers".to_string());
+        let action = Action::Binding { binding_id };
+
+        // Act
+        let conflict = service.bind_chord(&config, Some(chord(&[leader, b])), None, action);
+
+        // Assert
+        assert_eq!(conflict, Some(plain));
+        assert_eq!(*events.lock().unwrap(), vec![]);
+    }
+
+    #[test]
+    fn press_group_chord_keys() {
+        // Arrange
+        let (mut service, _) = setup_service();
+        let leader = Hotkey::new(Modifiers::CONTROL, Code::Space);
+        let b = Hotkey::new(Modifiers::empty(), Code::KeyB);
+        let mut config = Config::default();
+        let action = setup_group(&mut config, None);
+        config
+            .set_chord(&action, Some(chord(&[leader, b])))
+            .unwrap();
+        service.press_chord_key(&config, leader);
+
+        // Act
+        let result = service.press_chord_key(&config, b);
+
+        // Assert
+        assert_eq!(result, Some(action));
+    }
+
+    fn exclude(config: &mut Config, action: &Action, app: &str) {
+        let context = HotkeyContext {
+            only_in: Vec::new(),
+            excluded: vec![app.to_string()],
+        };
+        config.set_context(action, context).unwrap();
+    }
+
+    #[test]
+    fn excluded_app_in_front_unbinds() {
+        // Arrange
+        let (mut service, events) = setup_service();
+        let hotkey = Hotkey::new(Modifiers::SUPER, Code::KeyF);
+        let mut config = Config::default();
+        let action = setup_group(&mut config, Some(hotkey));
+        exclude(&mut config, &action, "editor");
+
+        // Act
+        service.set_frontmost(&config, Some("editor".to_string()));
+        service.set_frontmost(&config, Some("chat".to_string()));
+
+        // Assert
+        assert_eq!(
+            *events.lock().unwrap(),
+            vec![Unregister(hotkey), Register(hotkey, action)]
+        );
+    }
+
+    #[test]
+    fn bind_context_excluding_frontmost_app() {
+        // Arrange
+        let (mut service, events) = setup_service();
+        let hotkey = Hotkey::new(Modifiers::SUPER, Code::KeyF);
+        let mut config = Config::default();
+        let action = setup_group(&mut config, Some(hotkey));
+        service.set_frontmost(&config, Some("editor".to_string()));
+        let context = HotkeyContext {
+            only_in: Vec::new(),
+            excluded: vec!["editor".to_string()],
+        };
+
+        // Act
+        service.bind_context(&config, &action, &context);
+        config.set_context(&action, context).unwrap();
+        let result = service.bind_hotkey(&config, None, Some(hotkey), action);
+
+        // Assert
+        assert_eq!(result, Ok(()));
+        assert_eq!(*events.lock().unwrap(), vec![Unregister(hotkey)]);
+    }
 }
@@ -0,0 +1,240 @@
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use crate::models::{Hotkey, Trigger};
+
+const DOUBLE_TAP_WINDOW: Duration = Duration::from_millis(300);
+const HOLD_THRESHOLD: Duration = Duration::from_millis(500);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum KeyState {
+    Down {
+        at: Instant,
+    },
+    /// Released after a short press, waiting whether a second tap follows
+    Released {
+        at: Instant,
+    },
+    /// Reported already, only waiting for the release
+    Consumed,
+}
+
+struct TrackedKey {
+    bound: Vec<Trigger>,
+    state: KeyState,
+}
+
+impl TrackedKey {
+    fn binds(&self, trigger: Trigger) -> bool {
+        self.bound.contains(&trigger)
+    }
+}
+
+/// Turns presses and releases into triggers. Time is passed in, so it can be tested without
+/// waiting; `tick` has to be called by `deadline` to report holds and single taps.
+#[derive(Default)]
+pub struct TriggerMachine {
+    keys: HashMap<Hotkey, TrackedKey>,
+}
+
+impl TriggerMachine {
+    /// `bound` are the triggers with an action, a tap fires right away if there are no others
+    pub fn press(&mut self, hotkey: Hotkey, bound: Vec<Trigger>, now: Instant) -> Option<Trigger> {
+        if let Some(tracked) = self.keys.get_mut(&hotkey)
+            && let KeyState::Released { at } = tracked.state
+            && !elapsed(at, now, DOUBLE_TAP_WINDOW)
+        {
+            tracked.state = KeyState::Consumed;
+            return Some(Trigger::DoubleTap);
+        }
+        let mut tracked = TrackedKey {
+            bound,
+            state: KeyState::Down { at: now },
+        };
+        let waits = tracked.binds(Trigger::DoubleTap) || tracked.binds(Trigger::Hold);
+        if !waits {
+            tracked.state = KeyState::Consumed;
+        }
+        self.keys.insert(hotkey, tracked);
+        (!waits).then_some(Trigger::Tap)
+    }
+
+    pub fn release(&mut self, hotkey: Hotkey, now: Instant) -> Option<Trigger> {
+        let tracked = self.keys.get_mut(&hotkey)?;
+        let KeyState::Down { at } = tracked.state else {
+            if tracked.state == KeyState::Consumed {
+                self.keys.remove(&hotkey);
+            }
+            return None;
+        };
+        // the tick may not have come round yet
+        if tracked.binds(Trigger::Hold) && elapsed(at, now, HOLD_THRESHOLD) {
+            self.keys.remove(&hotkey);
+            return Some(Trigger::Hold);
+        }
+        if tracked.binds(Trigger::DoubleTap) {
+            tracked.state = KeyState::Released { at: now };
+            return None;
+        }
+        self.keys.remove(&hotkey);
+        Some(Trigger::Tap)
+    }
+
+    /// Reports the holds and single taps whose time is up
+    pub fn tick(&mut self, now: Instant) -> Vec<(Hotkey, Trigger)> {
+        let mut fired = Vec::new();
+        for (hotkey, tracked) in &mut self.keys {
+            match tracked.state {
+                KeyState::Down { at }
+                    if tracked.binds(Trigger::Hold) && elapsed(at, now, HOLD_THRESHOLD) =>
+                {
+                    tracked.state = KeyState::Consumed;
+                    fired.push((*hotkey, Trigger::Hold));
+                }
+                KeyState::Released { at } if elapsed(at, now, DOUBLE_TAP_WINDOW) => {
+                    fired.push((*hotkey, Trigger::Tap));
+                }
+                _ => {}
+            }
+        }
+        self.keys.retain(|_, t| match t.state {
+            KeyState::Released { at } => !elapsed(at, now, DOUBLE_TAP_WINDOW),
+            _ => true,
+        });
+        fired
+    }
+
+    /// When `tick` may have something to report next
+    pub fn deadline(&self) -> Option<Instant> {
+        self.keys
+            .values()
+            .filter_map(|t| match t.state {
+                KeyState::Down { at } if t.binds(Trigger::Hold) => Some(at + HOLD_THRESHOLD),
+                KeyState::Released { at } => Some(at + DOUBLE_TAP_WINDOW),
+                _ => None,
+            })
+            .min()
+    }
+}
+
+fn elapsed(since: Instant, now: Instant, duration: Duration) -> bool {
+    now.saturating_duration_since(since) >= duration
+}
+
+#[cfg(test)]
+mod tests {
+    use global_hotkey::hotkey::{Code, Modifiers};
+
+    use super::*;
+
+    fn hotkey() -> Hotkey {
+        Hotkey::new(Modifiers::CONTROL, Code::KeyB)
+    }
+
+    fn ms(start: Instant, millis: u64) -> Instant {
+        start + Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn tap_fires_on_press_without_other_triggers() {
+        // Arrange
+        let mut machine = TriggerMachine::default();
+        let start = Instant::now();
+
+        // Act
+        let pressed = machine.press(hotkey(), vec![Trigger::Tap], start);
+        let released = machine.release(hotkey(), ms(start, 50));
+
+        // Assert
+        assert_eq!(pressed, Some(Trigger::Tap));
+        assert_eq!(released, None);
+        assert_eq!(machine.deadline(), None);
+    }
+
+    #[test]
+    fn double_tap() {
+        // Arrange
+        let mut machine = TriggerMachine::default();
+        let start = Instant::now();
+        let bound = vec![Trigger::Tap, Trigger::DoubleTap];
+
+        // Act
+        let events = [
+            machine.press(hotkey(), bound.clone(), start),
+            machine.release(hotkey(), ms(start, 50)),
+            machine.press(hotkey(), bound, ms(start, 200)),
+            machine.release(hotkey(), ms(start, 250)),
+        ];
+
+        // Assert
+        assert_eq!(events, [None, None, Some(Trigger::DoubleTap), None]);
+        assert_eq!(machine.tick(ms(start, 1000)), vec![]);
+    }
+
+    #[test]
+    fn single_tap_waits_for_the_double_tap_window() {
+        // Arrange
+        let mut machine = TriggerMachine::default();
+        let start = Instant::now();
+        machine.press(hotkey(), vec![Trigger::Tap, Trigger::DoubleTap], start);
+        machine.release(hotkey(), ms(start, 50));
+
+        // Act
+        let early = machine.tick(ms(start, 200));
+        let deadline = machine.deadline().unwrap();
+        let late = machine.tick(deadline);
+
+        // Assert
+        assert_eq!(early, vec![]);
+        assert_eq!(deadline, ms(start, 350));
+        assert_eq!(late, vec![(hotkey(), Trigger::Tap)]);
+        assert_eq!(machine.deadline(), None);
+    }
+
+    #[test]
+    fn hold_fires_while_held() {
+        // Arrange
+        let mut machine = TriggerMachine::default();
+        let start = Instant::now();
+        machine.press(hotkey(), vec![Trigger::Tap, Trigger::Hold], start);
+
+        // Act
+        let early = machine.tick(ms(start, 100));
+        let held = machine.tick(ms(start, 600));
+        let released = machine.release(hotkey(), ms(start, 900));
+
+        // Assert
+        assert_eq!(early, vec![]);
+        assert_eq!(held, vec![(hotkey(), Trigger::Hold)]);
+        assert_eq!(released, None);
+    }
+
+    #[test]
+    fn hold_fires_on_late_release() {
+        // Arrange
+        let mut machine = TriggerMachine::default();
+        let start = Instant::now();
+        machine.press(hotkey(), vec![Trigger::Tap, Trigger::Hold], start);
+
+        // Act
+        let released = machine.release(hotkey(), ms(start, 700));
+
+        // Assert
+        assert_eq!(released, Some(Trigger::Hold));
+    }
+
+    #[test]
+    fn short_press_is_a_tap_with_hold_bound() {
+        // Arrange
+        let mut machine = TriggerMachine::default();
+        let start = Instant::now();
+        machine.press(hotkey(), vec![Trigger::Tap, Trigger::Hold], start);
+
+        // Act
+        let released = machine.release(hotkey(), ms(start, 100));
+
+        // Assert
+        assert_eq!(released, Some(Trigger::Tap));
+        assert_eq!(machine.deadline(), None);
+    }
+}
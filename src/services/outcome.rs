@@ -0,0 +1,228 @@
+use std::collections::VecDeque;
+use std::fmt::{Display, Formatter};
+use std::time::{Duration, Instant};
+
+const HISTORY_LIMIT: usize = 50;
+
+/// How an action went, failures are kept as text to show them in the UI
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ActionOutcome {
+    Success,
+    /// Some of the work was done, e.g. a few members of a group could not be launched
+    Partial {
+        failures: Vec<String>,
+    },
+    Failed {
+        reason: String,
+    },
+    /// Nothing to do, which is not the user's fault, e.g. there is no previous group yet
+    Skipped {
+        reason: String,
+    },
+}
+
+impl ActionOutcome {
+    pub fn failed(reason: impl Display) -> Self {
+        ActionOutcome::Failed {
+            reason: reason.to_string(),
+        }
+    }
+
+    pub fn skipped(reason: impl Display) -> Self {
+        ActionOutcome::Skipped {
+            reason: reason.to_string(),
+        }
+    }
+
+    pub fn from_result<E: Display>(result: Result<(), E>) -> Self {
+        match result {
+            Ok(()) => ActionOutcome::Success,
+            Err(e) => ActionOutcome::failed(e),
+        }
+    }
+
+    /// Combines the outcomes of the parts of an action, it only failed if every part failed.
+    /// Skipped parts don't count, unless all of them were skipped.
+    pub fn combine(outcomes: impl IntoIterator<Item = ActionOutcome>) -> Self {
+        let mut failures = Vec::new();
+        let mut skipped = Vec::new();
+        let mut any_succeeded = false;
+        let mut any_failed = false;
+        for outcome in outcomes {
+            match outcome {
+                ActionOutcome::Success => any_succeeded = true,
+                ActionOutcome::Partial { failures: more } => {
+                    any_succeeded = true;
+                    failures.extend(more);
+                }
+                ActionOutcome::Failed { reason } => {
+                    any_failed = true;
+                    failures.push(reason);
+                }
+                ActionOutcome::Skipped { reason } => skipped.push(reason),
+            }
+        }
+        if !any_succeeded && !any_failed && !skipped.is_empty() {
+            ActionOutcome::Skipped {
+                reason: skipped.join("\n"),
+            }
+        } else if !any_failed && failures.is_empty() {
+            ActionOutcome::Success
+        } else if any_succeeded {
+            ActionOutcome::Partial { failures }
+        } else {
+            ActionOutcome::Failed {
+                reason: failures.join("\n"),
+            }
+        }
+    }
+
+    pub fn is_failure(&self) -> bool {
+        matches!(
+            self,
+            ActionOutcome::Partial { .. } | ActionOutcome::Failed { .. }
+        )
+    }
+}
+
+impl Display for ActionOutcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ActionOutcome::Success => write!(f, "Done"),
+            ActionOutcome::Partial { failures } => {
+                write!(f, "Partly done, {} failed", failures.len())?;
+                for failure in failures {
+                    write!(f, "\n{failure}")?;
+                }
+                Ok(())
+            }
+            ActionOutcome::Failed { reason } => write!(f, "Failed: {reason}"),
+            ActionOutcome::Skipped { reason } => write!(f, "Skipped: {reason}"),
+        }
+    }
+}
+
+/// An executed action and how it went
+#[derive(Debug, Clone, PartialEq)]
+pub struct ActionReport {
+    pub label: String, // the action described with group and binding names, for display
+    pub outcome: ActionOutcome,
+    pub at: Instant,
+}
+
+impl Display for ActionReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.label, self.outcome)
+    }
+}
+
+/// The most recent reports, oldest first
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct OutcomeHistory {
+    reports: VecDeque<ActionReport>,
+}
+
+impl OutcomeHistory {
+    pub fn push(&mut self, report: ActionReport) {
+        if self.reports.len() == HISTORY_LIMIT {
+            self.reports.pop_front();
+        }
+        self.reports.push_back(report);
+    }
+
+    /// All kept reports, newest first
+    pub fn recent(&self) -> impl Iterator<Item = &ActionReport> {
+        self.reports.iter().rev()
+    }
+
+    /// The reports of the given time span, newest first
+    pub fn within(&self, age: Duration) -> impl Iterator<Item = &ActionReport> {
+        self.recent().take_while(move |r| r.at.elapsed() <= age)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report(label: &str, outcome: ActionOutcome) -> ActionReport {
+        ActionReport {
+            label: label.to_string(),
+            outcome,
+            at: Instant::now(),
+        }
+    }
+
+    #[test]
+    fn combine_outcomes() {
+        // Arrange
+        let failed = || ActionOutcome::failed("nope");
+
+        // Act
+        let success = ActionOutcome::combine([ActionOutcome::Success, ActionOutcome::Success]);
+        let partial = ActionOutcome::combine([ActionOutcome::Success, failed()]);
+        let all_failed = ActionOutcome::combine([failed(), failed()]);
+        let nothing = ActionOutcome::combine([]);
+
+        // Assert
+        assert_eq!(success, ActionOutcome::Success);
+        assert_eq!(
+            partial,
+            ActionOutcome::Partial {
+                failures: vec!["nope".to_string()]
+            }
+        );
+        assert_eq!(all_failed, ActionOutcome::failed("nope\nnope"));
+        assert_eq!(nothing, ActionOutcome::Success);
+    }
+
+    #[test]
+    fn combine_keeps_nested_failures() {
+        // Arrange
+        let nested = ActionOutcome::Partial {
+            failures: vec!["a".to_string()],
+        };
+
+        // Act
+        let outcome = ActionOutcome::combine([nested, ActionOutcome::failed("b")]);
+
+        // Assert
+        assert_eq!(
+            outcome,
+            ActionOutcome::Partial {
+                failures: vec!["a".to_string(), "b".to_string()]
+            }
+        );
+    }
+
+    #[test]
+    fn combine_skipped() {
+        // Arrange
+        let skipped = || ActionOutcome::skipped("later");
+
+        // Act
+        let all_skipped = ActionOutcome::combine([skipped(), skipped()]);
+        let some_skipped = ActionOutcome::combine([ActionOutcome::Success, skipped()]);
+
+        // Assert
+        assert_eq!(all_skipped, ActionOutcome::skipped("later\nlater"));
+        assert_eq!(some_skipped, ActionOutcome::Success);
+    }
+
+    #[test]
+    fn history_is_bounded() {
+        // Arrange
+        let mut history = OutcomeHistory::default();
+
+        // Act
+        for i in 0..HISTORY_LIMIT + 5 {
+            history.push(report(&i.to_string(), ActionOutcome::Success));
+        }
+
+        // Assert
+        let recent: Vec<_> = history.within(Duration::from_secs(60)).collect();
+        assert_eq!(recent.len(), HISTORY_LIMIT);
+        assert_eq!(recent[0].label, (HISTORY_LIMIT + 4).to_string());
+        assert_eq!(recent[HISTORY_LIMIT - 1].label, "5");
+    }
+}
@@ -0,0 +1,110 @@
+use std::fs;
+use std::path::PathBuf;
+
+use log::warn;
+use serde::{Deserialize, Serialize};
+
+const APP_DIR: &str = "GroupCtrl";
+const STATE_FILE: &str = "state.json";
+
+/// What has to survive a restart, until the whole config is saved
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SavedState {
+    #[serde(default)]
+    pub hotkeys_paused: bool,
+}
+
+/// Reads and writes the saved state, problems are only logged as the app works without it
+pub struct StateStore {
+    path: Option<PathBuf>,
+}
+
+impl Default for StateStore {
+    fn default() -> Self {
+        Self { path: state_path() }
+    }
+}
+
+impl StateStore {
+    pub fn load(&self) -> SavedState {
+        let Some(path) = &self.path else {
+            return SavedState::default();
+        };
+        let json = match fs::read_to_string(path) {
+            Ok(json) => json,
+            Err(_) => return SavedState::default(), // nothing saved yet
+        };
+        serde_json::from_str(&json).unwrap_or_else(|e| {
+            warn!("Ignoring the saved state in {}: {e}", path.display());
+            SavedState::default()
+        })
+    }
+
+    pub fn save(&self, state: &SavedState) {
+        let Some(path) = &self.path else {
+            return;
+        };
+        let result = path
+            .parent()
+            .map_or(Ok(()), fs::create_dir_all)
+            .and_then(|()| fs::write(path, serde_json::to_string_pretty(state)?));
+        if let Err(e) = result {
+            warn!("Could not save the state to {}: {e}", path.display());
+        }
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn state_path() -> Option<PathBuf> {
+    let home = std::env::var("HOME").ok()?;
+    let dir = PathBuf::from(home).join("Library/Application Support");
+    Some(dir.join(APP_DIR).join(STATE_FILE))
+}
+
+#[cfg(target_os = "windows")]
+fn state_path() -> Option<PathBuf> {
+    let dir = PathBuf::from(std::env::var("APPDATA").ok()?);
+    Some(dir.join(APP_DIR).join(STATE_FILE))
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use super::*;
+
+    fn setup_store() -> StateStore {
+        let dir = std::env::temp_dir().join(Uuid::new_v4().to_string());
+        StateStore {
+            path: Some(dir.join(STATE_FILE)),
+        }
+    }
+
+    #[test]
+    fn load_nothing_saved() {
+        // Arrange
+        let store = setup_store();
+
+        // Act
+        let state = store.load();
+
+        // Assert
+        assert_eq!(state, SavedState::default());
+    }
+
+    #[test]
+    fn save_and_load() {
+        // Arrange
+        let store = setup_store();
+        let state = SavedState {
+            hotkeys_paused: true,
+        };
+
+        // Act
+        store.save(&state);
+        let loaded = store.load();
+
+        // Assert
+        assert_eq!(loaded, state);
+    }
+}
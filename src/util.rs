@@ -1,4 +1,7 @@
+use std::process::Child;
+
 use global_hotkey::hotkey::Code;
+use log::warn;
 
 pub fn capitalize(s: &str) -> String {
     s.chars()
@@ -14,3 +17,46 @@ pub fn is_modifier(code: &Code) -> bool {
         || code_str.contains("Alt")
         || code_str.contains("Shift")
 }
+
+/// Replaces a leading `~` with the user's home directory
+pub fn expand_home(s: &str) -> String {
+    let home = std::env::var("HOME").or_else(|_| std::env::var("USERPROFILE"));
+    match (s.strip_prefix('~'), home) {
+        (Some(rest), Ok(home)) if rest.is_empty() || rest.starts_with(['/', '\\']) => home + rest,
+        _ => s.to_string(),
+    }
+}
+
+/// Waits for the child on a thread of its own, so it doesn't linger as a zombie once it exits
+pub fn reap(mut child: Child, command: String) {
+    std::thread::spawn(move || match child.wait() {
+        Ok(status) if !status.success() => warn!("'{command}' exited with {status}"),
+        Ok(_) => {}
+        Err(e) => warn!("Could not wait for '{command}': {e}"),
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expand_home_only_replaces_leading_tilde() {
+        // Arrange
+        let home = std::env::var("HOME")
+            .or_else(|_| std::env::var("USERPROFILE"))
+            .unwrap();
+
+        // Act
+        let bare = expand_home("~");
+        let nested = expand_home("~/Documents");
+        let other_user = expand_home("~alice/Documents");
+        let inner = expand_home("/tmp/~");
+
+        // Assert
+        assert_eq!(bare, home);
+        assert_eq!(nested, format!("{home}/Documents"));
+        assert_eq!(other_user, "~alice/Documents");
+        assert_eq!(inner, "/tmp/~");
+    }
+}